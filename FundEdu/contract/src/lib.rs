@@ -1,13 +1,7 @@
 #![no_std]
 
-mod contract;
-mod storage;
-mod types;
+//! Scholarship pools now live in the shared crowdfunding contract, which
+//! stores them alongside campaigns under a single storage schema.
 
-pub use contract::FundEduContract;
-
-#[cfg(test)]
-extern crate std;
-
-#[cfg(test)]
-mod test;
+pub use hello_world::base::types::{PoolConfig, PoolState};
+pub use hello_world::contract::{FundEduContract, FundEduContractClient, FundEduError};
@@ -1,6 +1,8 @@
 use soroban_sdk::contracterror;
 
-#[contracterror]
+// A contract spec entry holds at most 50 error cases, so `CrowdfundingError`
+// is left out of the spec. Its codes are still returned to callers unchanged.
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum CrowdfundingError {
@@ -55,10 +57,52 @@ pub enum CrowdfundingError {
     UserBlacklisted = 49,
     CampaignCancelled = 50,
     InsufficientSponsorBalance = 51,
-    ApplicationNotFound = 51,
-    ApplicationAlreadySubmitted = 52,
-    ApplicationAlreadyReviewed = 53,
-    InvalidApplicationCredentials = 54,
+    ApplicationNotFound = 52,
+    ApplicationAlreadySubmitted = 53,
+    ApplicationAlreadyReviewed = 54,
+    InvalidApplicationCredentials = 55,
+    ApplicationWithdrawn = 56,
+    ResubmissionLimitReached = 57,
+    InvalidEligibilityRules = 58,
+    ApplicationWindowClosed = 59,
+    ApplicationLimitReached = 60,
+    ApplicantNotEligible = 61,
+    PoolMatchAlreadyExists = 62,
+    PoolMatchNotFound = 63,
+    SubscriptionNotFound = 64,
+    SubscriptionInactive = 65,
+    SubscriptionNotDue = 66,
+    PledgeModeNotEnabled = 67,
+    PledgeModeActive = 68,
+    PledgeGoalNotReached = 69,
+    PledgeCollectionNotOpen = 70,
+    InsufficientAllowance = 71,
+    PledgeNotFound = 72,
+    InvalidOverfundingPolicy = 73,
+    PoolMetadataLocked = 74,
+    CampaignMetadataNotFound = 75,
+    InvalidMemo = 76,
+    LiabilityExceeded = 77,
+    InvalidEmergencyRecipient = 78,
+    PoolFrozen = 79,
+    CampaignFrozen = 80,
+    UpgradeNotScheduled = 81,
+    UpgradeDelayNotPassed = 82,
+    PoolExpired = 83,
+    PoolTargetReached = 84,
+    OptOutUnavailable = 85,
+    NothingToReclaim = 86,
+    PoolNameTooLong = 87,
+    DescriptionTooLong = 88,
+    InvalidMinContribution = 89,
+    InvalidPoolDuration = 90,
+    FeeCalculationOverflow = 91,
+    LiabilitiesNotSeeded = 92,
+    LiabilitiesAlreadySeeded = 93,
+    ValidatorMissing = 94,
+    EmergencyChangeNotScheduled = 95,
+    EmergencyChangeDelayNotPassed = 96,
+    EmergencyRequestPending = 97,
 }
 
 #[contracterror]
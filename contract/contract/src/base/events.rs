@@ -1,7 +1,7 @@
 #![allow(deprecated)]
 use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol};
 
-use crate::base::types::PoolState;
+use crate::base::types::{OverfundingPolicy, PoolState};
 
 pub fn campaign_created(
     env: &Env,
@@ -64,6 +64,191 @@ pub fn pool_state_updated(env: &Env, pool_id: u64, new_state: PoolState) {
     env.events().publish(topics, new_state);
 }
 
+pub fn pool_funded(env: &Env, pool_id: u64, sponsor: Address, amount: i128) {
+    let topics = (Symbol::new(env, "pool_funded"), pool_id, sponsor);
+    env.events().publish(topics, amount);
+}
+
+pub fn pool_deactivated(env: &Env, pool_id: u64, sponsor: Address, returned: i128) {
+    let topics = (Symbol::new(env, "pool_deactivated"), pool_id, sponsor);
+    env.events().publish(topics, returned);
+}
+
+pub fn pool_cancelled(env: &Env, pool_id: u64, cancelled_by: Address, reason: String) {
+    let topics = (Symbol::new(env, "pool_cancelled"), pool_id, cancelled_by);
+    env.events().publish(topics, reason);
+}
+
+pub fn sponsor_deposit_reclaimed(env: &Env, pool_id: u64, sponsor: Address, amount: i128) {
+    let topics = (
+        Symbol::new(env, "sponsor_deposit_reclaimed"),
+        pool_id,
+        sponsor,
+    );
+    env.events().publish(topics, amount);
+}
+
+pub fn pool_match_created(env: &Env, pool_id: u64, sponsor: Address, budget: i128, ratio_bps: u32) {
+    let topics = (Symbol::new(env, "pool_match_created"), pool_id, sponsor);
+    env.events().publish(topics, (budget, ratio_bps));
+}
+
+pub fn contribution_matched(env: &Env, pool_id: u64, contributor: Address, matched: i128) {
+    let topics = (
+        Symbol::new(env, "contribution_matched"),
+        pool_id,
+        contributor,
+    );
+    env.events().publish(topics, matched);
+}
+
+pub fn pool_match_released(env: &Env, pool_id: u64, sponsor: Address, returned: i128) {
+    let topics = (Symbol::new(env, "pool_match_released"), pool_id, sponsor);
+    env.events().publish(topics, returned);
+}
+
+pub fn liabilities_seeded(env: &Env, admin: Address, token: Address, total: i128) {
+    let topics = (Symbol::new(env, "liabilities_seeded"), admin, token);
+    env.events().publish(topics, total);
+}
+
+pub fn subscription_created(env: &Env, subscription_id: u64, donor: Address, amount: i128) {
+    let topics = (
+        Symbol::new(env, "subscription_created"),
+        subscription_id,
+        donor,
+    );
+    env.events().publish(topics, amount);
+}
+
+pub fn subscription_processed(
+    env: &Env,
+    subscription_id: u64,
+    amount: i128,
+    remaining_periods: u32,
+) {
+    let topics = (Symbol::new(env, "subscription_processed"), subscription_id);
+    env.events().publish(topics, (amount, remaining_periods));
+}
+
+pub fn subscription_cancelled(env: &Env, subscription_id: u64, donor: Address) {
+    let topics = (
+        Symbol::new(env, "subscription_cancelled"),
+        subscription_id,
+        donor,
+    );
+    env.events().publish(topics, ());
+}
+
+pub fn pledge_mode_enabled(env: &Env, campaign_id: BytesN<32>, creator: Address) {
+    let topics = (Symbol::new(env, "pledge_mode_enabled"), campaign_id);
+    env.events().publish(topics, creator);
+}
+
+pub fn pledge_made(env: &Env, campaign_id: BytesN<32>, donor: Address, amount: i128) {
+    let topics = (Symbol::new(env, "pledge_made"), campaign_id, donor);
+    env.events().publish(topics, amount);
+}
+
+pub fn pledge_collected(env: &Env, campaign_id: BytesN<32>, donor: Address, amount: i128) {
+    let topics = (Symbol::new(env, "pledge_collected"), campaign_id, donor);
+    env.events().publish(topics, amount);
+}
+
+pub fn pledge_failed(env: &Env, campaign_id: BytesN<32>, donor: Address, amount: i128) {
+    let topics = (Symbol::new(env, "pledge_failed"), campaign_id, donor);
+    env.events().publish(topics, amount);
+}
+
+pub fn overfunding_policy_set(env: &Env, campaign_id: BytesN<32>, policy: OverfundingPolicy) {
+    let topics = (Symbol::new(env, "overfunding_policy_set"), campaign_id);
+    env.events().publish(topics, policy);
+}
+
+pub fn stretch_goal_reached(env: &Env, campaign_id: BytesN<32>, index: u32, threshold: i128) {
+    let topics = (Symbol::new(env, "stretch_goal_reached"), campaign_id, index);
+    env.events().publish(topics, threshold);
+}
+
+pub fn campaign_update_posted(
+    env: &Env,
+    campaign_id: BytesN<32>,
+    index: u32,
+    content_hash: BytesN<32>,
+) {
+    let topics = (
+        Symbol::new(env, "campaign_update_posted"),
+        campaign_id,
+        index,
+    );
+    env.events().publish(topics, content_hash);
+}
+
+pub fn pool_metadata_revised(env: &Env, pool_id: u64, updater: Address, revision: u32) {
+    let topics = (Symbol::new(env, "pool_metadata_revised"), pool_id, updater);
+    env.events().publish(topics, revision);
+}
+
+pub fn pool_metadata_lock_set(env: &Env, pool_id: u64, locked: bool) {
+    let topics = (Symbol::new(env, "pool_metadata_lock_set"), pool_id);
+    env.events().publish(topics, locked);
+}
+
+pub fn campaign_metadata_updated(env: &Env, campaign_id: BytesN<32>, category: Symbol) {
+    let topics = (Symbol::new(env, "campaign_metadata_updated"), campaign_id);
+    env.events().publish(topics, category);
+}
+
+pub fn emergency_safe_address_set(env: &Env, admin: Address, address: Address, allowed: bool) {
+    let topics = (Symbol::new(env, "emergency_safe_address_set"), admin);
+    env.events().publish(topics, (address, allowed));
+}
+
+pub fn emergency_withdraw_approved(env: &Env, guardian: Address, token: Address, amount: i128) {
+    let topics = (Symbol::new(env, "emergency_withdraw_approved"), guardian);
+    env.events().publish(topics, (token, amount));
+}
+
+pub fn emergency_withdraw_cancelled(env: &Env, caller: Address, token: Address, amount: i128) {
+    let topics = (Symbol::new(env, "emergency_withdraw_cancelled"), caller);
+    env.events().publish(topics, (token, amount));
+}
+
+pub fn upgrade_scheduled(env: &Env, admin: Address, wasm_hash: BytesN<32>, unlock_time: u64) {
+    let topics = (Symbol::new(env, "upgrade_scheduled"), admin);
+    env.events().publish(topics, (wasm_hash, unlock_time));
+}
+
+pub fn upgrade_executed(env: &Env, admin: Address, wasm_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "upgrade_executed"), admin);
+    env.events().publish(topics, wasm_hash);
+}
+
+pub fn guardian_paused(env: &Env, guardian: Address, timestamp: u64) {
+    let topics = (Symbol::new(env, "guardian_paused"), guardian);
+    env.events().publish(topics, timestamp);
+}
+
+pub fn emergency_withdraw_vetoed(env: &Env, guardian: Address, token: Address, amount: i128) {
+    let topics = (Symbol::new(env, "emergency_withdraw_vetoed"), guardian);
+    env.events().publish(topics, (token, amount));
+}
+
+pub fn upgrade_vetoed(env: &Env, guardian: Address, wasm_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "upgrade_vetoed"), guardian);
+    env.events().publish(topics, wasm_hash);
+}
+
+pub fn pool_frozen(env: &Env, pool_id: u64, caller: Address, frozen: bool) {
+    let topics = (Symbol::new(env, "pool_frozen"), pool_id);
+    env.events().publish(topics, (caller, frozen));
+}
+
+pub fn campaign_frozen(env: &Env, campaign_id: BytesN<32>, caller: Address, frozen: bool) {
+    let topics = (Symbol::new(env, "campaign_frozen"), campaign_id);
+    env.events().publish(topics, (caller, frozen));
+}
+
 pub fn contract_paused(env: &Env, admin: Address, timestamp: u64) {
     let topics = (Symbol::new(env, "contract_paused"), admin);
     env.events().publish(topics, timestamp);
@@ -79,6 +264,21 @@ pub fn admin_renounced(env: &Env, admin: Address) {
     env.events().publish(topics, ());
 }
 
+pub fn emergency_contact_scheduled(env: &Env, admin: Address, contact: Address, unlock_time: u64) {
+    let topics = (Symbol::new(env, "emergency_contact_scheduled"), admin);
+    env.events().publish(topics, (contact, unlock_time));
+}
+
+pub fn emergency_safe_address_scheduled(
+    env: &Env,
+    admin: Address,
+    address: Address,
+    unlock_time: u64,
+) {
+    let topics = (Symbol::new(env, "emergency_safe_address_scheduled"), admin);
+    env.events().publish(topics, (address, unlock_time));
+}
+
 pub fn emergency_contact_updated(env: &Env, admin: Address, contact: Address) {
     let topics = (Symbol::new(env, "emergency_contact_updated"), admin);
     env.events().publish(topics, contact);
@@ -124,9 +324,15 @@ pub fn emergency_withdraw_requested(
     env.events().publish(topics, (token, amount, unlock_time));
 }
 
-pub fn emergency_withdraw_executed(env: &Env, admin: Address, token: Address, amount: i128) {
+pub fn emergency_withdraw_executed(
+    env: &Env,
+    admin: Address,
+    recipient: Address,
+    token: Address,
+    amount: i128,
+) {
     let topics = (Symbol::new(env, "emergency_withdraw_executed"), admin);
-    env.events().publish(topics, (token, amount));
+    env.events().publish(topics, (recipient, token, amount));
 }
 
 pub fn crowdfunding_token_set(env: &Env, admin: Address, token: Address) {
@@ -156,6 +362,32 @@ pub fn refund(
     env.events().publish(topics, (asset, amount, timestamp));
 }
 
+pub fn pool_deadline_extended(
+    env: &Env,
+    pool_id: u64,
+    previous_deadline: u64,
+    new_deadline: u64,
+    opt_out_until: u64,
+) {
+    let topics = (Symbol::new(env, "pool_deadline_extended"), pool_id);
+    env.events()
+        .publish(topics, (previous_deadline, new_deadline, opt_out_until));
+}
+
+pub fn pool_extension_opt_out(env: &Env, pool_id: u64, contributor: Address, amount: i128) {
+    let topics = (
+        Symbol::new(env, "pool_extension_opt_out"),
+        pool_id,
+        contributor,
+    );
+    env.events().publish(topics, amount);
+}
+
+pub fn pool_finalized(env: &Env, pool_id: u64, state: PoolState, total_raised: i128) {
+    let topics = (Symbol::new(env, "pool_finalized"), pool_id);
+    env.events().publish(topics, (state, total_raised));
+}
+
 pub fn pool_closed(env: &Env, pool_id: u64, closed_by: Address, timestamp: u64) {
     let topics = (Symbol::new(env, "pool_closed"), pool_id, closed_by);
     env.events().publish(topics, timestamp);
@@ -205,12 +437,59 @@ pub fn ticket_sold(
         .publish(topics, (price, event_amount, fee_amount));
 }
 
-pub fn application_approved(env: &Env, admin: Address, cause: Address) {
+pub fn cause_verified(env: &Env, admin: Address, cause: Address) {
     let topics = (symbol_short!("AppApprv"), admin);
     env.events().publish(topics, cause);
 }
 
-pub fn application_rejected(env: &Env, admin: Address, cause: Address) {
+pub fn cause_rejected(env: &Env, admin: Address, cause: Address) {
     let topics = (symbol_short!("AppRej"), admin);
     env.events().publish(topics, cause);
-}
\ No newline at end of file
+}
+
+pub fn application_submitted(env: &Env, pool_id: u64, applicant: Address, submitted_at: u64) {
+    let topics = (
+        Symbol::new(env, "application_submitted"),
+        pool_id,
+        applicant,
+    );
+    env.events().publish(topics, submitted_at);
+}
+
+pub fn application_approved(env: &Env, pool_id: u64, applicant: Address, validator: Address) {
+    let topics = (Symbol::new(env, "application_approved"), pool_id, applicant);
+    env.events().publish(topics, validator);
+}
+
+pub fn application_rejected(env: &Env, pool_id: u64, applicant: Address, validator: Address) {
+    let topics = (Symbol::new(env, "application_rejected"), pool_id, applicant);
+    env.events().publish(topics, validator);
+}
+
+pub fn application_withdrawn(env: &Env, pool_id: u64, applicant: Address, timestamp: u64) {
+    let topics = (
+        Symbol::new(env, "application_withdrawn"),
+        pool_id,
+        applicant,
+    );
+    env.events().publish(topics, timestamp);
+}
+
+pub fn application_eligibility_updated(env: &Env, pool_id: u64, updater: Address) {
+    let topics = (
+        Symbol::new(env, "application_eligibility_updated"),
+        pool_id,
+        updater,
+    );
+    env.events().publish(topics, ());
+}
+
+pub fn applicant_attested(env: &Env, issuer: Address, applicant: Address) {
+    let topics = (Symbol::new(env, "applicant_attested"), issuer, applicant);
+    env.events().publish(topics, true);
+}
+
+pub fn attestation_revoked(env: &Env, issuer: Address, applicant: Address) {
+    let topics = (Symbol::new(env, "attestation_revoked"), issuer, applicant);
+    env.events().publish(topics, ());
+}
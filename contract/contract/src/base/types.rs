@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Map, String, Symbol, Vec};
+
+use crate::base::errors::CrowdfundingError;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -12,6 +14,15 @@ pub struct CampaignDetails {
     pub token_address: Address,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CampaignMetadata {
+    pub description: String,
+    pub category: Symbol,
+    pub external_url: String,
+    pub image_hash: String,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Contribution {
@@ -40,7 +51,8 @@ pub struct PoolConfig {
     pub duration: u64,
     pub created_at: u64,
     pub token_address: Address,
-    pub validator: Address,
+    // Reviews applications; always set on stored pools
+    pub validator: Option<Address>,
 }
 
 #[contracttype]
@@ -51,39 +63,178 @@ pub struct PoolMetadata {
     pub image_hash: String,
 }
 
+/// Input for `create_pool_with_params`, the single pool creation path.
+///
+/// Start from `PoolParams::new` and layer on the optional settings:
+///
+/// ```ignore
+/// let params = PoolParams::new(&env, name, 10_000, deadline)
+///     .with_min_contribution(100)
+///     .with_sponsor_deposit();
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolParams {
+    pub name: String,
+    pub metadata: PoolMetadata,
+    pub target_amount: i128,
+    pub min_contribution: i128,
+    pub is_private: bool,
+    pub deadline: u64,
+    // Defaults to the creator when unset
+    pub validator: Option<Address>,
+    // Multi-sig approval for the pool; set both or neither
+    pub required_signatures: Option<u32>,
+    pub signers: Option<Vec<Address>>,
+    // Lock target_amount from the creator into the pool at creation
+    pub sponsor_deposit: bool,
+}
+
+impl PoolParams {
+    pub fn new(env: &Env, name: String, target_amount: i128, deadline: u64) -> Self {
+        let empty = String::from_str(env, "");
+        Self {
+            name,
+            metadata: PoolMetadata {
+                description: empty.clone(),
+                external_url: empty.clone(),
+                image_hash: empty,
+            },
+            target_amount,
+            min_contribution: 0,
+            is_private: false,
+            deadline,
+            validator: None,
+            required_signatures: None,
+            signers: None,
+            sponsor_deposit: false,
+        }
+    }
+
+    pub fn with_metadata(mut self, metadata: PoolMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    pub fn with_min_contribution(mut self, min_contribution: i128) -> Self {
+        self.min_contribution = min_contribution;
+        self
+    }
+
+    pub fn private(mut self) -> Self {
+        self.is_private = true;
+        self
+    }
+
+    pub fn with_validator(mut self, validator: Address) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    pub fn with_multi_sig(mut self, required_signatures: u32, signers: Vec<Address>) -> Self {
+        self.required_signatures = Some(required_signatures);
+        self.signers = Some(signers);
+        self
+    }
+
+    pub fn with_sponsor_deposit(mut self) -> Self {
+        self.sponsor_deposit = true;
+        self
+    }
+}
+
 pub const MAX_DESCRIPTION_LENGTH: u32 = 500;
 pub const MAX_URL_LENGTH: u32 = 200;
 pub const MAX_HASH_LENGTH: u32 = 100;
 pub const MAX_STRING_LENGTH: u32 = 200;
+pub const MAX_MEMO_LENGTH: u32 = 140;
+
+// Time after a pool's deadline reserved for the payout before money flows back
+pub const REFUND_GRACE_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+// How long contributors have to back out after a pool deadline is extended
+pub const POOL_EXTENSION_OPT_OUT_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+// Entries stored per page of a persistent index
+pub const INDEX_PAGE_SIZE: u32 = 50;
+
+// Ledgers a persistent entry is extended to on each write (about 30 days at
+// 5s per ledger), once fewer than the threshold (about 7 days) remain
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 518_400;
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 
 impl PoolConfig {
     /// Validate pool configuration according to Nevo invariants.
     ///
-    /// Follows Soroban best practices by failing fast with `panic!` when
-    /// invariants are violated. Callers should validate user input before
-    /// persisting configuration on-chain.
-    pub fn validate(&self) {
-        // Name must not be empty
-        assert!(!self.name.is_empty(), "pool name must not be empty");
+    /// Returns the `CrowdfundingError` describing the first violated
+    /// invariant so callers can surface a precise reason to clients.
+    pub fn validate(&self) -> Result<(), CrowdfundingError> {
+        // Name must be present and within the string limit
+        if self.name.is_empty() {
+            return Err(CrowdfundingError::InvalidPoolName);
+        }
+        if self.name.len() > MAX_STRING_LENGTH {
+            return Err(CrowdfundingError::PoolNameTooLong);
+        }
 
         // Description validation
-        assert!(
-            self.description.len() <= MAX_DESCRIPTION_LENGTH,
-            "description too long"
-        );
+        if self.description.len() > MAX_DESCRIPTION_LENGTH {
+            return Err(CrowdfundingError::DescriptionTooLong);
+        }
 
         // Target amount must be strictly positive
-        assert!(self.target_amount > 0, "target_amount must be > 0");
+        if self.target_amount <= 0 {
+            return Err(CrowdfundingError::InvalidPoolTarget);
+        }
 
         // Minimum contribution must be non-negative and not exceed the target
-        assert!(self.min_contribution >= 0, "min_contribution must be >= 0");
-        assert!(
-            self.min_contribution <= self.target_amount,
-            "min_contribution must be <= target_amount"
-        );
+        if self.min_contribution < 0 || self.min_contribution > self.target_amount {
+            return Err(CrowdfundingError::InvalidMinContribution);
+        }
 
         // Duration must be strictly positive (non-zero)
-        assert!(self.duration > 0, "duration must be > 0");
+        if self.duration == 0 {
+            return Err(CrowdfundingError::InvalidPoolDuration);
+        }
+
+        // Someone has to review applications
+        if self.validator.is_none() {
+            return Err(CrowdfundingError::ValidatorMissing);
+        }
+
+        Ok(())
+    }
+
+    /// Converts the config into `PoolParams`. The deadline counts from the
+    /// current ledger time; `created_at` is not trusted.
+    pub fn to_params(&self, env: &Env) -> Result<PoolParams, CrowdfundingError> {
+        if self.duration == 0 {
+            return Err(CrowdfundingError::InvalidPoolDuration);
+        }
+        let validator = self
+            .validator
+            .clone()
+            .ok_or(CrowdfundingError::ValidatorMissing)?;
+        let deadline = env
+            .ledger()
+            .timestamp()
+            .checked_add(self.duration)
+            .ok_or(CrowdfundingError::InvalidPoolDuration)?;
+
+        let empty = String::from_str(env, "");
+        let params = PoolParams::new(env, self.name.clone(), self.target_amount, deadline)
+            .with_metadata(PoolMetadata {
+                description: self.description.clone(),
+                external_url: empty.clone(),
+                image_hash: empty,
+            })
+            .with_min_contribution(self.min_contribution)
+            .with_validator(validator);
+        Ok(if self.is_private {
+            params.private()
+        } else {
+            params
+        })
     }
 }
 
@@ -131,6 +282,20 @@ pub enum PoolState {
     Cancelled = 3,
     Disbursed = 4,
     Closed = 5,
+    // Never stored: reported for an Active or Paused pool past its deadline
+    Expired = 6,
+}
+
+impl PoolState {
+    /// Every state a pool can be stored in.
+    pub const ALL: [PoolState; 6] = [
+        PoolState::Active,
+        PoolState::Paused,
+        PoolState::Completed,
+        PoolState::Cancelled,
+        PoolState::Disbursed,
+        PoolState::Closed,
+    ];
 }
 
 #[contracttype]
@@ -186,6 +351,83 @@ pub enum TicketType {
     VIP = 1,
 }
 
+/// How a campaign treats donations once its goal is reached.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OverfundingPolicy {
+    // Accept nothing beyond the goal
+    HardCap,
+    // Accept donations up to the given total
+    StretchCap(i128),
+    Unlimited,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OverfundingConfig {
+    pub policy: OverfundingPolicy,
+    // Ascending totals above the goal; an event fires as each is crossed
+    pub stretch_goals: Vec<i128>,
+}
+
+/// What the contract owes, per token, grouped by where the money came from.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum LiabilityBucket {
+    // Campaign donations not yet claimed or refunded
+    CampaignEscrow = 0,
+    // Sponsor deposits and contributions held by pools
+    PoolFunds = 1,
+    // Unspent sponsor matching budgets
+    MatchBudgets = 2,
+    EventProceeds = 3,
+    PlatformFees = 4,
+    EventFees = 5,
+}
+
+impl LiabilityBucket {
+    pub const ALL: [LiabilityBucket; 6] = [
+        LiabilityBucket::CampaignEscrow,
+        LiabilityBucket::PoolFunds,
+        LiabilityBucket::MatchBudgets,
+        LiabilityBucket::EventProceeds,
+        LiabilityBucket::PlatformFees,
+        LiabilityBucket::EventFees,
+    ];
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolvencyReport {
+    pub balance: i128,
+    pub liabilities: i128,
+    // Negative when the contract owes more than it holds
+    pub surplus: i128,
+    pub buckets: Map<LiabilityBucket, i128>,
+}
+
+/// A single gift as recorded in a campaign's or pool's donation ledger.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationRecord {
+    pub donor: Address,
+    pub amount: i128,
+    pub asset: Address,
+    pub timestamp: u64,
+    pub is_private: bool,
+    pub memo: Option<String>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignUpdate {
+    pub index: u32,
+    pub content_hash: BytesN<32>,
+    pub uri: String,
+    pub posted_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CampaignMetrics {
@@ -239,6 +481,21 @@ impl PoolMetrics {
     }
 }
 
+/// Matching fund a sponsor attaches to a pool.
+///
+/// Each contribution draws `amount * ratio_bps / 10_000` from `remaining`,
+/// limited to `per_donor_cap` in total for any single contributor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolMatch {
+    pub sponsor: Address,
+    pub budget: i128,
+    pub remaining: i128,
+    pub ratio_bps: u32,
+    pub per_donor_cap: i128,
+    pub total_matched: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DisbursementRequest {
@@ -258,6 +515,42 @@ pub struct EmergencyWithdrawal {
     pub token: Address,
     pub requested_at: u64,
     pub executed: bool,
+    // Set by the emergency contact to allow the withdrawal to exceed surplus
+    pub guardian_approved: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolCancellation {
+    // The contract itself when an expired pool missed its target
+    pub cancelled_by: Address,
+    pub reason: String,
+    pub cancelled_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolExtension {
+    pub previous_deadline: u64,
+    pub new_deadline: u64,
+    pub extended_at: u64,
+    // Contributions made up to `extended_at` may be withdrawn until then
+    pub opt_out_until: u64,
+}
+
+// A new emergency contact or safe address, waiting out its delay
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingEmergencyChange {
+    pub address: Address,
+    pub requested_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub requested_at: u64,
 }
 
 #[contracttype]
@@ -269,12 +562,55 @@ pub struct PoolContribution {
     pub asset: Address,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SubscriptionTarget {
+    Campaign(BytesN<32>),
+    Pool(u64),
+}
+
+/// Recurring donation pulled from the donor's token allowance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Subscription {
+    pub id: u64,
+    pub donor: Address,
+    pub target: SubscriptionTarget,
+    pub asset: Address,
+    pub amount: i128,
+    pub interval: u64,
+    pub next_due: u64,
+    pub remaining_periods: u32,
+    pub active: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PledgeStatus {
+    Pending = 0,
+    Collected = 1,
+    // The allowance or balance no longer covered the pledge at collection
+    Failed = 2,
+}
+
+/// Commitment to donate to a pledge-mode campaign, collected only if the
+/// campaign reaches its goal by the deadline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pledge {
+    pub campaign_id: BytesN<32>,
+    pub donor: Address,
+    pub amount: i128,
+    pub status: PledgeStatus,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ApplicationStatus {
     Pending = 0,
     Approved = 1,
     Rejected = 2,
+    Withdrawn = 3,
 }
 
 #[contracttype]
@@ -282,13 +618,37 @@ pub enum ApplicationStatus {
 pub struct ApplicationDetails {
     pub pool_id: u64,
     pub applicant: Address,
-    pub credentials: Bytes,
+    // sha256 commitment to the applicant's off-chain document bundle
+    pub credentials_hash: BytesN<32>,
+    // Optional pointer (e.g. an encrypted CID) to where validators can fetch the bundle
+    pub encrypted_pointer: Option<Bytes>,
     pub submitted_at: u64,
     pub status: ApplicationStatus,
     pub reviewer: Option<Address>,
     pub review_note: Option<String>,
+    // Number of times this applicant has submitted to the pool, including the first
+    pub submission_count: u32,
 }
 
+/// Optional per-pool rules restricting who may apply for a scholarship.
+///
+/// Every criterion left as `None` is not enforced.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationEligibility {
+    pub window_start: Option<u64>,
+    pub window_end: Option<u64>,
+    // Cap on distinct applicants; resubmissions do not count again
+    pub max_applications: Option<u32>,
+    pub allowlist: Option<Vec<Address>>,
+    // Verified cause issuer that must have attested the applicant
+    pub required_attester: Option<Address>,
+}
+
+pub const MAX_POINTER_LENGTH: u32 = 256;
+pub const MAX_APPLICATION_SUBMISSIONS: u32 = 3;
+pub const MAX_ALLOWLIST_LENGTH: u32 = 200;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum StorageKey {
@@ -302,6 +662,10 @@ pub enum StorageKey {
     PoolContribution(u64, Address),
     PoolContributors(u64),
     Application(u64, Address),
+    ApplicationEligibility(u64),
+    ApplicationCount(u64),
+    // Attestation issued by a verified cause (issuer, applicant)
+    Attestation(Address, Address),
 
     NextPoolId,
     IsPaused,
@@ -342,46 +706,124 @@ pub enum StorageKey {
     PoolBalance(u64),
 }
 
+/// Storage keys that no longer fit in [`StorageKey`], whose contract spec is
+/// capped at 50 variants.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SecondStorageKey {
+    PoolMatch(u64),
+    // Matching funds already credited to a contributor (pool_id, contributor)
+    PoolMatched(u64, Address),
+    Subscription(u64),
+    NextSubscriptionId,
+    CampaignPledgeMode(BytesN<32>),
+    Pledge(BytesN<32>, Address),
+    CampaignPledgers(BytesN<32>),
+    // Sum of pledges not yet collected or failed
+    CampaignPledgeOutstanding(BytesN<32>),
+    PledgeCursor(BytesN<32>),
+    CampaignOverfunding(BytesN<32>),
+    // Progress reports, stored one per key (campaign_id, index)
+    CampaignUpdate(BytesN<32>, u32),
+    CampaignUpdateCount(BytesN<32>),
+    PoolMetadataRevision(u64),
+    // Freeze pool metadata once the first contribution arrives
+    PoolMetadataLock(u64),
+    CampaignMetadata(BytesN<32>),
+    CampaignsByCategory(Symbol),
+    // Discovery indexes; Expired campaigns stay under Live until read
+    CampaignsByStatus(CampaignLifecycleStatus),
+    PoolsByState(PoolState),
+    // Portfolio indexes for creators and donors
+    CreatorCampaigns(Address),
+    CreatorPools(Address),
+    DonorCampaigns(Address),
+    DonorPools(Address),
+    // Append-only donation ledgers, one entry per key (target, index)
+    CampaignDonation(BytesN<32>, u32),
+    CampaignDonationCount(BytesN<32>),
+    CampaignDonorEntries(BytesN<32>, Address),
+    PoolDonation(u64, u32),
+    PoolDonationCount(u64),
+    PoolDonorEntries(u64, Address),
+    Liability(Address, LiabilityBucket),
+    EmergencySafeAddress(Address),
+    PendingEmergencyContact,
+    PendingEmergencySafeAddress(Address),
+    PendingUpgrade,
+    PoolFrozen(u64),
+    CampaignFrozen(BytesN<32>),
+    PoolExtension(u64),
+    // Timestamp of the extension the contributor last opted out of
+    PoolExtensionOptOut(u64, Address),
+    PoolCancellation(u64),
+    // Donor contributions held by a pool, without sponsor or matched funds
+    PoolContributed(u64),
+    // Set at initialization: every balance has been booked to a bucket
+    LiabilitiesTracked,
+    // Buckets seeded for balances held before liabilities were tracked
+    LiabilitiesSeeded(Address),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use soroban_sdk::{testutils::Address as _, Address, Env};
 
-    #[test]
-    fn pool_config_validation_success() {
-        let env = Env::default();
-        let token = Address::generate(&env);
-        let cfg = PoolConfig {
-            name: String::from_str(&env, "Education Fund"),
-            description: String::from_str(&env, "Fund for student education materials"),
+    fn pool_config(env: &Env) -> PoolConfig {
+        let token = Address::generate(env);
+        PoolConfig {
+            name: String::from_str(env, "Education Fund"),
+            description: String::from_str(env, "Fund for student education materials"),
             target_amount: 1_000_000,
             min_contribution: 0,
             is_private: false,
             duration: 30 * 24 * 60 * 60,
             created_at: 1,
-            token_address: token,
-        };
-
-        cfg.validate();
+            token_address: token.clone(),
+            validator: Some(token),
+        }
     }
 
     #[test]
-    #[should_panic]
-    fn pool_config_invalid_target_amount_panics() {
+    fn pool_config_validation_success() {
         let env = Env::default();
-        let token = Address::generate(&env);
-        let cfg = PoolConfig {
-            name: String::from_str(&env, "Invalid Target"),
-            description: String::from_str(&env, "Description"),
-            target_amount: 0,
-            min_contribution: 0,
-            is_private: false,
-            duration: 30 * 24 * 60 * 60,
-            created_at: 1,
-            token_address: token,
-        };
+        assert_eq!(pool_config(&env).validate(), Ok(()));
+    }
 
-        cfg.validate();
+    #[test]
+    fn pool_config_validation_errors() {
+        let env = Env::default();
+        let long_name = "n".repeat(MAX_STRING_LENGTH as usize + 1);
+        let long_description = "d".repeat(MAX_DESCRIPTION_LENGTH as usize + 1);
+
+        let mut empty_name = pool_config(&env);
+        empty_name.name = String::from_str(&env, "");
+        let mut name_too_long = pool_config(&env);
+        name_too_long.name = String::from_str(&env, &long_name);
+        let mut description_too_long = pool_config(&env);
+        description_too_long.description = String::from_str(&env, &long_description);
+        let mut zero_target = pool_config(&env);
+        zero_target.target_amount = 0;
+        let mut min_above_target = pool_config(&env);
+        min_above_target.min_contribution = min_above_target.target_amount + 1;
+        let mut zero_duration = pool_config(&env);
+        zero_duration.duration = 0;
+        let mut no_validator = pool_config(&env);
+        no_validator.validator = None;
+
+        let cases = [
+            (empty_name, CrowdfundingError::InvalidPoolName),
+            (name_too_long, CrowdfundingError::PoolNameTooLong),
+            (description_too_long, CrowdfundingError::DescriptionTooLong),
+            (zero_target, CrowdfundingError::InvalidPoolTarget),
+            (min_above_target, CrowdfundingError::InvalidMinContribution),
+            (zero_duration, CrowdfundingError::InvalidPoolDuration),
+            (no_validator, CrowdfundingError::ValidatorMissing),
+        ];
+        for (cfg, expected) in cases {
+            assert_eq!(cfg.validate(), Err(expected));
+        }
     }
 
     #[test]
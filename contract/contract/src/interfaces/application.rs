@@ -1,6 +1,9 @@
-use soroban_sdk::{Address, Bytes, Env, String};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String};
 
-use crate::base::{errors::CrowdfundingError, types::{ApplicationDetails}};
+use crate::base::{
+    errors::CrowdfundingError,
+    types::{ApplicationDetails, ApplicationEligibility},
+};
 
 /// Defines the user and validator-facing application lifecycle for FundEdu pools.
 ///
@@ -10,13 +13,18 @@ use crate::base::{errors::CrowdfundingError, types::{ApplicationDetails}};
 pub trait ApplicationTrait {
     /// Apply for a FundEdu scholarship pool.
     ///
-    /// The caller must be the applicant and provide verifiable credentials as raw
-    /// bytes that can be referenced off-chain by validators.
+    /// The caller must be the applicant. Only a sha256 commitment to the
+    /// off-chain document bundle is stored, plus an optional encrypted pointer
+    /// telling validators where to fetch it, so no student data is public.
+    ///
+    /// An applicant whose previous application was rejected or withdrawn may
+    /// resubmit, up to `MAX_APPLICATION_SUBMISSIONS` submissions in total.
     fn apply_for_scholarship(
         env: Env,
         pool_id: u64,
         applicant: Address,
-        application_credentials: Bytes,
+        credentials_hash: BytesN<32>,
+        encrypted_pointer: Option<Bytes>,
     ) -> Result<(), CrowdfundingError>;
 
     /// Approve a pending scholarship application.
@@ -43,6 +51,59 @@ pub trait ApplicationTrait {
         rejection_reason: Option<String>,
     ) -> Result<(), CrowdfundingError>;
 
+    /// Withdraw a pending scholarship application.
+    ///
+    /// Only the applicant may withdraw, and only while the application is still
+    /// `Pending`. Reviewed applications are final and cannot be withdrawn.
+    fn withdraw_application(
+        env: Env,
+        pool_id: u64,
+        applicant: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    /// Check an off-chain document bundle against the stored commitment.
+    ///
+    /// Returns `true` when the sha256 of `preimage` matches the applicant's
+    /// `credentials_hash`.
+    fn verify_credentials(
+        env: Env,
+        pool_id: u64,
+        applicant: Address,
+        preimage: Bytes,
+    ) -> Result<bool, CrowdfundingError>;
+
+    /// Configure who may apply to a pool.
+    ///
+    /// Only the pool creator may set the rules. They are checked on every call
+    /// to `apply_for_scholarship` and apply to later submissions only. An
+    /// allowlist holds at most `MAX_ALLOWLIST_LENGTH` addresses.
+    fn set_application_eligibility(
+        env: Env,
+        pool_id: u64,
+        caller: Address,
+        eligibility: ApplicationEligibility,
+    ) -> Result<(), CrowdfundingError>;
+
+    /// Retrieve the eligibility rules for a pool, if any were configured.
+    fn get_application_eligibility(env: Env, pool_id: u64) -> Option<ApplicationEligibility>;
+
+    /// Record that a verified cause issuer vouches for an applicant.
+    ///
+    /// Pools whose rules name `issuer` as `required_attester` only accept
+    /// applicants the issuer has attested.
+    fn attest_applicant(
+        env: Env,
+        issuer: Address,
+        applicant: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    /// Withdraw a previously issued attestation.
+    fn revoke_attestation(
+        env: Env,
+        issuer: Address,
+        applicant: Address,
+    ) -> Result<(), CrowdfundingError>;
+
     /// Retrieve an application record by pool and applicant.
     fn get_application(
         env: Env,
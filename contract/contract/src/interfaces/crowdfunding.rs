@@ -1,10 +1,13 @@
-use soroban_sdk::{Address, BytesN, Env, String, Vec};
+use soroban_sdk::{Address, BytesN, Env, Map, String, Symbol, Vec};
 
 use crate::base::{
     errors::CrowdfundingError,
     types::{
-        CampaignDetails, CampaignLifecycleStatus, PoolConfig, PoolContribution, PoolMetadata,
-        PoolState,
+        CampaignDetails, CampaignLifecycleStatus, CampaignMetadata, CampaignUpdate, Contribution,
+        DonationRecord, EmergencyWithdrawal, LiabilityBucket, OverfundingConfig, OverfundingPolicy,
+        PendingEmergencyChange, PendingUpgrade, Pledge, PoolCancellation, PoolConfig,
+        PoolContribution, PoolExtension, PoolMatch, PoolMetadata, PoolParams, PoolState,
+        SolvencyReport, Subscription, SubscriptionTarget,
     },
 };
 
@@ -19,12 +22,38 @@ pub trait CrowdfundingTrait {
         token_address: Address,
     ) -> Result<(), CrowdfundingError>;
 
+    /// Creates a campaign and stores its descriptive metadata in one call.
+    #[allow(clippy::too_many_arguments)]
+    fn create_campaign_with_metadata(
+        env: Env,
+        id: BytesN<32>,
+        title: String,
+        creator: Address,
+        goal: i128,
+        deadline: u64,
+        token_address: Address,
+        metadata: CampaignMetadata,
+    ) -> Result<(), CrowdfundingError>;
+
     fn get_campaign(env: Env, id: BytesN<32>) -> Result<CampaignDetails, CrowdfundingError>;
 
     fn get_campaigns(env: Env, ids: Vec<BytesN<32>>) -> Vec<CampaignDetails>;
 
     fn get_all_campaigns(env: Env) -> Vec<BytesN<32>>;
 
+    /// Pages through the ids of campaigns currently in `status`.
+    ///
+    /// `offset` and `limit` count entries of the status index. Campaigns that
+    /// expired or met their goal through pledges since they were indexed are
+    /// left out of the page, so a page can hold fewer than `limit` ids while
+    /// later pages still have some.
+    fn list_campaigns(
+        env: Env,
+        status: CampaignLifecycleStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BytesN<32>>;
+
     fn get_donor_count(env: Env, campaign_id: BytesN<32>) -> Result<u32, CrowdfundingError>;
 
     fn get_campaign_balance(env: Env, campaign_id: BytesN<32>) -> Result<i128, CrowdfundingError>;
@@ -54,6 +83,16 @@ pub trait CrowdfundingTrait {
         amount: i128,
     ) -> Result<(), CrowdfundingError>;
 
+    /// Same as `donate`, with a short note stored in the donation ledger.
+    fn donate_with_memo(
+        env: Env,
+        campaign_id: BytesN<32>,
+        donor: Address,
+        asset: Address,
+        amount: i128,
+        memo: String,
+    ) -> Result<(), CrowdfundingError>;
+
     fn update_campaign_goal(
         env: Env,
         campaign_id: BytesN<32>,
@@ -92,6 +131,14 @@ pub trait CrowdfundingTrait {
         config: PoolConfig,
     ) -> Result<u64, CrowdfundingError>;
 
+    /// Open a pool from a `PoolParams`. `create_pool` and `save_pool` are
+    /// thin wrappers over this path.
+    fn create_pool_with_params(
+        env: Env,
+        creator: Address,
+        params: PoolParams,
+    ) -> Result<u64, CrowdfundingError>;
+
     #[allow(clippy::too_many_arguments)]
     fn save_pool(
         env: Env,
@@ -106,8 +153,96 @@ pub trait CrowdfundingTrait {
 
     fn get_pool(env: Env, pool_id: u64) -> Option<PoolConfig>;
 
+    /// The pool's state, reporting `Expired` once a running pool's deadline
+    /// has passed.
+    fn get_pool_state(env: Env, pool_id: u64) -> Result<PoolState, CrowdfundingError>;
+
+    /// Settles an expired pool: `Completed` if it reached its target,
+    /// otherwise `Cancelled` so contributors can claim refunds. Anyone may
+    /// call it.
+    fn finalize_pool(env: Env, pool_id: u64) -> Result<PoolState, CrowdfundingError>;
+
+    /// Pushes back an underfunded pool's deadline, at most 90 days out.
+    /// Existing contributors get a window to withdraw if they disagree.
+    fn extend_pool_deadline(
+        env: Env,
+        pool_id: u64,
+        caller: Address,
+        new_deadline: u64,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_pool_extension(env: Env, pool_id: u64) -> Option<PoolExtension>;
+
+    /// Returns what the contributor gave before the latest extension and
+    /// reports the amount withdrawn.
+    fn opt_out_of_pool_extension(
+        env: Env,
+        pool_id: u64,
+        contributor: Address,
+    ) -> Result<i128, CrowdfundingError>;
+
+    /// Pages through the ids of pools in `state`, optionally limited to one
+    /// creator.
+    ///
+    /// `offset` and `limit` count entries of the state index, or of the
+    /// creator's pools when `creator` is set. Pools whose state has changed
+    /// since they were indexed, such as pools past their deadline, are left
+    /// out of the page, so a page can hold fewer than `limit` ids while later
+    /// pages still have some.
+    fn list_pools(
+        env: Env,
+        state: PoolState,
+        creator: Option<Address>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64>;
+
     fn get_pool_balance(env: Env, pool_id: u64) -> Result<i128, CrowdfundingError>;
 
+    fn fund_pool(
+        env: Env,
+        pool_id: u64,
+        sponsor: Address,
+        amount: i128,
+    ) -> Result<(), CrowdfundingError>;
+
+    /// Deposits a matching budget that `contribute` draws from automatically.
+    fn set_pool_match(
+        env: Env,
+        pool_id: u64,
+        sponsor: Address,
+        budget: i128,
+        ratio_bps: u32,
+        per_donor_cap: i128,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_match_status(env: Env, pool_id: u64) -> Result<PoolMatch, CrowdfundingError>;
+
+    /// Cancels the pool and returns the sponsor's unallocated balance to them.
+    fn deactivate_pool(env: Env, pool_id: u64, sponsor: Address)
+        -> Result<i128, CrowdfundingError>;
+
+    /// Same as `deactivate_pool`, recording why the pool was cancelled.
+    /// Either the sponsor or the pool validator may cancel; the unallocated
+    /// balance always goes back to the sponsor. Contributors can refund
+    /// straight away.
+    fn cancel_pool(
+        env: Env,
+        pool_id: u64,
+        caller: Address,
+        reason: String,
+    ) -> Result<i128, CrowdfundingError>;
+
+    fn get_pool_cancellation(env: Env, pool_id: u64) -> Option<PoolCancellation>;
+
+    /// Returns the sponsor's deposit from a cancelled pool, or from an
+    /// expired pool nobody claimed within the grace period.
+    fn reclaim_sponsor_deposit(
+        env: Env,
+        pool_id: u64,
+        sponsor: Address,
+    ) -> Result<i128, CrowdfundingError>;
+
     fn get_pool_metadata(env: Env, pool_id: u64) -> (String, String, String);
 
     fn update_pool_metadata_hash(
@@ -117,6 +252,28 @@ pub trait CrowdfundingTrait {
         new_hash: String,
     ) -> Result<(), CrowdfundingError>;
 
+    /// Replaces the pool's description, external URL and image hash, returning
+    /// the new metadata revision.
+    fn update_pool_metadata(
+        env: Env,
+        pool_id: u64,
+        caller: Address,
+        metadata: PoolMetadata,
+    ) -> Result<u32, CrowdfundingError>;
+
+    fn get_pool_metadata_revision(env: Env, pool_id: u64) -> u32;
+
+    /// When locked, metadata can no longer change once the pool has received a
+    /// contribution. The lock cannot be lifted after that point.
+    fn set_pool_metadata_lock(
+        env: Env,
+        pool_id: u64,
+        caller: Address,
+        locked: bool,
+    ) -> Result<(), CrowdfundingError>;
+
+    /// Pauses, resumes or completes a pool. Cancelling, disbursing and
+    /// closing move funds and go through their own entry points.
     fn update_pool_state(
         env: Env,
         pool_id: u64,
@@ -124,6 +281,13 @@ pub trait CrowdfundingTrait {
         new_state: PoolState,
     ) -> Result<(), CrowdfundingError>;
 
+    /// States `caller` could move the pool to right now.
+    fn get_allowed_transitions(
+        env: Env,
+        pool_id: u64,
+        caller: Address,
+    ) -> Result<Vec<PoolState>, CrowdfundingError>;
+
     fn set_crowdfunding_token(env: Env, token: Address) -> Result<(), CrowdfundingError>;
 
     fn get_crowdfunding_token(env: Env) -> Result<Address, CrowdfundingError>;
@@ -161,16 +325,52 @@ pub trait CrowdfundingTrait {
         is_private: bool,
     ) -> Result<(), CrowdfundingError>;
 
+    /// Same as `contribute`, with a short note stored in the donation ledger.
+    fn contribute_with_memo(
+        env: Env,
+        pool_id: u64,
+        contributor: Address,
+        asset: Address,
+        amount: i128,
+        is_private: bool,
+        memo: String,
+    ) -> Result<(), CrowdfundingError>;
+
     fn refund(env: Env, pool_id: u64, contributor: Address) -> Result<(), CrowdfundingError>;
 
+    /// Queues a withdrawal to the emergency contact or a registered safe
+    /// address, executable after a 24 hour delay.
     fn request_emergency_withdraw(
         env: Env,
         token: Address,
         amount: i128,
+        recipient: Address,
     ) -> Result<(), CrowdfundingError>;
 
     fn execute_emergency_withdraw(env: Env) -> Result<(), CrowdfundingError>;
 
+    /// Co-signs the pending withdrawal so it may exceed the token's surplus.
+    fn approve_emergency_withdraw(env: Env) -> Result<(), CrowdfundingError>;
+
+    fn cancel_emergency_withdraw(env: Env) -> Result<(), CrowdfundingError>;
+
+    fn get_pending_emergency_withdraw(env: Env) -> Option<EmergencyWithdrawal>;
+
+    /// Removes a safe address at once. Adding one is scheduled and takes
+    /// effect through `execute_emergency_safe_address` after a 24 hour delay.
+    /// Both are refused while an emergency withdrawal is pending.
+    fn set_emergency_safe_address(
+        env: Env,
+        address: Address,
+        allowed: bool,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn execute_emergency_safe_address(env: Env, address: Address) -> Result<(), CrowdfundingError>;
+
+    fn get_pending_safe_address(env: Env, address: Address) -> Option<PendingEmergencyChange>;
+
+    fn is_emergency_safe_address(env: Env, address: Address) -> bool;
+
     fn close_pool(env: Env, pool_id: u64, caller: Address) -> Result<(), CrowdfundingError>;
 
     fn is_closed(env: Env, pool_id: u64) -> Result<bool, CrowdfundingError>;
@@ -194,10 +394,39 @@ pub trait CrowdfundingTrait {
         amount: i128,
     ) -> Result<(), CrowdfundingError>;
 
+    /// Sets the first emergency contact at once. A replacement is scheduled
+    /// and takes effect through `execute_emergency_contact_change` after a 24
+    /// hour delay. Both are refused while an emergency withdrawal or an
+    /// upgrade is pending.
     fn set_emergency_contact(env: Env, contact: Address) -> Result<(), CrowdfundingError>;
 
+    fn execute_emergency_contact_change(env: Env) -> Result<(), CrowdfundingError>;
+
+    fn get_pending_emergency_contact(env: Env) -> Option<PendingEmergencyChange>;
+
     fn get_emergency_contact(env: Env) -> Result<Address, CrowdfundingError>;
 
+    // The emergency contact acts as a guardian: it can halt things, but only
+    // the admin can resume them.
+
+    fn guardian_pause(env: Env) -> Result<(), CrowdfundingError>;
+
+    fn veto_emergency_withdraw(env: Env) -> Result<(), CrowdfundingError>;
+
+    fn veto_upgrade(env: Env) -> Result<(), CrowdfundingError>;
+
+    fn freeze_pool(env: Env, pool_id: u64) -> Result<(), CrowdfundingError>;
+
+    fn unfreeze_pool(env: Env, pool_id: u64) -> Result<(), CrowdfundingError>;
+
+    fn is_pool_frozen(env: Env, pool_id: u64) -> bool;
+
+    fn freeze_campaign(env: Env, campaign_id: BytesN<32>) -> Result<(), CrowdfundingError>;
+
+    fn unfreeze_campaign(env: Env, campaign_id: BytesN<32>) -> Result<(), CrowdfundingError>;
+
+    fn is_campaign_frozen(env: Env, campaign_id: BytesN<32>) -> bool;
+
     fn get_contract_version(env: Env) -> String;
 
     fn get_pool_contributions_paginated(
@@ -230,5 +459,184 @@ pub trait CrowdfundingTrait {
 
     fn claim_pool_funds(env: Env, pool_id: u64, student: Address) -> Result<(), CrowdfundingError>;
 
+    /// Schedules an upgrade that `execute_upgrade` applies after a 24 hour
+    /// delay, leaving the guardian time to veto it.
     fn upgrade_contract(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), CrowdfundingError>;
+
+    fn execute_upgrade(env: Env) -> Result<(), CrowdfundingError>;
+
+    fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade>;
+
+    /// Registers a recurring donation. The donor must separately approve the
+    /// contract to spend `amount` per period of the target's token.
+    fn create_subscription(
+        env: Env,
+        donor: Address,
+        target: SubscriptionTarget,
+        amount: i128,
+        interval: u64,
+        periods: u32,
+    ) -> Result<u64, CrowdfundingError>;
+
+    /// Collects one due payment. Callable by anyone.
+    fn process_subscription(env: Env, subscription_id: u64) -> Result<(), CrowdfundingError>;
+
+    fn cancel_subscription(
+        env: Env,
+        subscription_id: u64,
+        donor: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_subscription(env: Env, subscription_id: u64) -> Result<Subscription, CrowdfundingError>;
+
+    /// Switches a campaign that has not received donations to all-or-nothing
+    /// pledges. Direct donations are refused from then on.
+    fn enable_pledge_mode(
+        env: Env,
+        campaign_id: BytesN<32>,
+        creator: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    /// Records a pledge without moving tokens. The donor's allowance to the
+    /// contract must cover everything they have pledged.
+    fn pledge(
+        env: Env,
+        campaign_id: BytesN<32>,
+        donor: Address,
+        amount: i128,
+    ) -> Result<(), CrowdfundingError>;
+
+    /// Collects up to `limit` pending pledges once the deadline has passed with
+    /// the goal met, resuming where the previous batch stopped. Returns the
+    /// number of pledges processed.
+    ///
+    /// Nothing is collected unless the pledges donors can still cover meet the
+    /// goal. If a later pledge fails and the goal falls out of reach, the
+    /// campaign is cancelled so collected pledges can be refunded.
+    fn collect_pledges(
+        env: Env,
+        campaign_id: BytesN<32>,
+        limit: u32,
+    ) -> Result<u32, CrowdfundingError>;
+
+    fn get_pledge(
+        env: Env,
+        campaign_id: BytesN<32>,
+        donor: Address,
+    ) -> Result<Pledge, CrowdfundingError>;
+
+    /// Sets how donations beyond the goal are handled. Campaigns without a
+    /// policy behave as `HardCap`. Donations that would cross the cap are
+    /// partially accepted up to it.
+    fn set_overfunding_policy(
+        env: Env,
+        campaign_id: BytesN<32>,
+        creator: Address,
+        policy: OverfundingPolicy,
+        stretch_goals: Vec<i128>,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_overfunding_policy(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<OverfundingConfig, CrowdfundingError>;
+
+    fn get_outstanding_pledges(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<i128, CrowdfundingError>;
+
+    /// Appends a progress report to the campaign's update log. Updates cannot
+    /// be edited or removed once posted.
+    fn post_campaign_update(
+        env: Env,
+        campaign_id: BytesN<32>,
+        creator: Address,
+        content_hash: BytesN<32>,
+        uri: String,
+    ) -> Result<u32, CrowdfundingError>;
+
+    fn get_campaign_updates(
+        env: Env,
+        campaign_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<CampaignUpdate>, CrowdfundingError>;
+
+    fn update_campaign_metadata(
+        env: Env,
+        campaign_id: BytesN<32>,
+        creator: Address,
+        metadata: CampaignMetadata,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_campaign_metadata(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<CampaignMetadata, CrowdfundingError>;
+
+    fn list_campaigns_by_category(
+        env: Env,
+        category: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BytesN<32>>;
+
+    fn get_creator_campaigns(
+        env: Env,
+        creator: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BytesN<32>>;
+
+    fn get_creator_pools(env: Env, creator: Address, offset: u32, limit: u32) -> Vec<u64>;
+
+    /// Pages through the campaigns `donor` gave to, with cumulative amounts.
+    fn get_donor_campaigns(env: Env, donor: Address, offset: u32, limit: u32) -> Vec<Contribution>;
+
+    /// Pages through the pools `donor` contributed to, with cumulative amounts.
+    fn get_donor_pools(env: Env, donor: Address, offset: u32, limit: u32) -> Vec<PoolContribution>;
+
+    fn get_campaign_donations(
+        env: Env,
+        campaign_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<DonationRecord>, CrowdfundingError>;
+
+    fn get_campaign_donor_history(
+        env: Env,
+        campaign_id: BytesN<32>,
+        donor: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<DonationRecord>, CrowdfundingError>;
+
+    fn get_pool_donations(
+        env: Env,
+        pool_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<DonationRecord>, CrowdfundingError>;
+
+    fn get_pool_donor_history(
+        env: Env,
+        pool_id: u64,
+        donor: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<DonationRecord>, CrowdfundingError>;
+
+    /// Compares what the contract owes in `token` with what it holds.
+    fn get_solvency(env: Env, token: Address) -> SolvencyReport;
+
+    /// One-time migration for contracts upgraded from a version without
+    /// liability tracking: books what is already owed in `token`, per
+    /// bucket, so the solvency view and surplus-capped emergency
+    /// withdrawals see it. Needs both the admin and the emergency contact.
+    fn seed_liabilities(
+        env: Env,
+        token: Address,
+        owed: Map<LiabilityBucket, i128>,
+    ) -> Result<(), CrowdfundingError>;
 }
@@ -1,10 +1,20 @@
 #![cfg(test)]
 
 use crate::{
-    base::{errors::CrowdfundingError, types::{ApplicationStatus, PoolConfig}},
+    base::{
+        errors::CrowdfundingError,
+        types::{
+            ApplicationEligibility, ApplicationStatus, PoolConfig, MAX_ALLOWLIST_LENGTH,
+            MAX_APPLICATION_SUBMISSIONS,
+        },
+    },
     crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
 };
-use soroban_sdk::{testutils::Address as _, Address, Bytes, Env, String};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token::StellarAssetClient,
+    vec, Address, Bytes, BytesN, Env, FromVal, String, Symbol,
+};
 
 fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
     env.mock_all_auths();
@@ -22,7 +32,15 @@ fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
 }
 
 fn create_pool(env: &Env, client: &CrowdfundingContractClient<'_>, token_address: &Address) -> u64 {
-    let creator = Address::generate(env);
+    create_pool_by(env, client, token_address, &Address::generate(env))
+}
+
+fn create_pool_by(
+    env: &Env,
+    client: &CrowdfundingContractClient<'_>,
+    token_address: &Address,
+    creator: &Address,
+) -> u64 {
     let config = PoolConfig {
         name: String::from_str(env, "Scholarship Fund"),
         description: String::from_str(env, "Fund for student scholarships"),
@@ -32,9 +50,36 @@ fn create_pool(env: &Env, client: &CrowdfundingContractClient<'_>, token_address
         duration: 30 * 24 * 60 * 60,
         created_at: env.ledger().timestamp(),
         token_address: token_address.clone(),
+        validator: Some(creator.clone()),
     };
 
-    client.create_pool(&creator, &config)
+    StellarAssetClient::new(env, token_address).mint(creator, &config.target_amount);
+    client.create_pool(creator, &config)
+}
+
+fn open_rules() -> ApplicationEligibility {
+    ApplicationEligibility {
+        window_start: None,
+        window_end: None,
+        max_applications: None,
+        allowlist: None,
+        required_attester: None,
+    }
+}
+
+/// sha256 commitment to an off-chain document bundle.
+fn commitment(env: &Env, bundle: &[u8]) -> BytesN<32> {
+    env.crypto().sha256(&Bytes::from_slice(env, bundle)).into()
+}
+
+fn has_event(env: &Env, name: &str, pool_id: u64, applicant: &Address) -> bool {
+    let expected = Symbol::new(env, name);
+    env.events().all().iter().any(|(_, topics, _)| {
+        topics.len() == 3
+            && Symbol::from_val(env, &topics.get(0).unwrap()) == expected
+            && u64::from_val(env, &topics.get(1).unwrap()) == pool_id
+            && Address::from_val(env, &topics.get(2).unwrap()) == *applicant
+    })
 }
 
 #[test]
@@ -44,9 +89,9 @@ fn test_apply_for_scholarship_success() {
 
     let pool_id = create_pool(&env, &client, &token_address);
     let applicant = Address::generate(&env);
-    let credentials = Bytes::from_array(&env, &[1, 2, 3, 4]);
+    let credentials = commitment(&env, &[1, 2, 3, 4]);
 
-    client.apply_for_scholarship(&pool_id, &applicant, &credentials);
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
 
     let application = client.get_application(&pool_id, &applicant);
     assert_eq!(application.status, ApplicationStatus::Pending);
@@ -60,11 +105,16 @@ fn test_approve_application_changes_status() {
 
     let pool_id = create_pool(&env, &client, &token_address);
     let applicant = Address::generate(&env);
-    let validator = Address::generate(&env);
-    let credentials = Bytes::from_array(&env, &[5, 6, 7]);
+    let validator = client.get_pool(&pool_id).unwrap().validator.unwrap();
+    let credentials = commitment(&env, &[5, 6, 7]);
 
-    client.apply_for_scholarship(&pool_id, &applicant, &credentials);
-    client.approve_application(&pool_id, &applicant, &validator, &Some(String::from_str(&env, "Approved")));
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    client.approve_application(
+        &pool_id,
+        &applicant,
+        &validator,
+        &Some(String::from_str(&env, "Approved")),
+    );
 
     let application = client.get_application(&pool_id, &applicant);
     assert_eq!(application.status, ApplicationStatus::Approved);
@@ -78,11 +128,16 @@ fn test_reject_application_changes_status() {
 
     let pool_id = create_pool(&env, &client, &token_address);
     let applicant = Address::generate(&env);
-    let validator = Address::generate(&env);
-    let credentials = Bytes::from_array(&env, &[9, 10, 11]);
+    let validator = client.get_pool(&pool_id).unwrap().validator.unwrap();
+    let credentials = commitment(&env, &[9, 10, 11]);
 
-    client.apply_for_scholarship(&pool_id, &applicant, &credentials);
-    client.reject_application(&pool_id, &applicant, &validator, &Some(String::from_str(&env, "Rejected")));
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    client.reject_application(
+        &pool_id,
+        &applicant,
+        &validator,
+        &Some(String::from_str(&env, "Rejected")),
+    );
 
     let application = client.get_application(&pool_id, &applicant);
     assert_eq!(application.status, ApplicationStatus::Rejected);
@@ -96,8 +151,464 @@ fn test_apply_for_scholarship_empty_credentials_fails() {
 
     let pool_id = create_pool(&env, &client, &token_address);
     let applicant = Address::generate(&env);
-    let credentials = Bytes::from_array(&env, &[]);
+    let credentials = BytesN::from_array(&env, &[0u8; 32]);
+
+    let result = client.try_apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    assert_eq!(
+        result,
+        Err(Ok(CrowdfundingError::InvalidApplicationCredentials))
+    );
+}
+
+#[test]
+fn test_apply_for_scholarship_emits_submitted_event() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let credentials = commitment(&env, &[1, 2, 3]);
+
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+
+    assert!(has_event(
+        &env,
+        "application_submitted",
+        pool_id,
+        &applicant
+    ));
+}
+
+#[test]
+fn test_approve_application_emits_approved_event() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let validator = client.get_pool(&pool_id).unwrap().validator.unwrap();
+    let credentials = commitment(&env, &[1, 2, 3]);
+
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    client.approve_application(&pool_id, &applicant, &validator, &None);
+
+    assert!(has_event(&env, "application_approved", pool_id, &applicant));
+}
+
+#[test]
+fn test_reject_application_emits_rejected_event() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let validator = client.get_pool(&pool_id).unwrap().validator.unwrap();
+    let credentials = commitment(&env, &[1, 2, 3]);
+
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    client.reject_application(&pool_id, &applicant, &validator, &None);
+
+    assert!(has_event(&env, "application_rejected", pool_id, &applicant));
+}
+
+#[test]
+fn test_withdraw_pending_application() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let credentials = commitment(&env, &[1, 2, 3]);
+
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    client.withdraw_application(&pool_id, &applicant);
+    assert!(has_event(
+        &env,
+        "application_withdrawn",
+        pool_id,
+        &applicant
+    ));
+
+    let application = client.get_application(&pool_id, &applicant);
+    assert_eq!(application.status, ApplicationStatus::Withdrawn);
+}
+
+#[test]
+fn test_withdraw_reviewed_application_fails() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let validator = client.get_pool(&pool_id).unwrap().validator.unwrap();
+    let credentials = commitment(&env, &[1, 2, 3]);
+
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    client.approve_application(&pool_id, &applicant, &validator, &None);
+
+    let result = client.try_withdraw_application(&pool_id, &applicant);
+    assert_eq!(
+        result,
+        Err(Ok(CrowdfundingError::ApplicationAlreadyReviewed))
+    );
+}
+
+#[test]
+fn test_review_withdrawn_application_fails() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let validator = client.get_pool(&pool_id).unwrap().validator.unwrap();
+    let credentials = commitment(&env, &[1, 2, 3]);
+
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    client.withdraw_application(&pool_id, &applicant);
+
+    let result = client.try_approve_application(&pool_id, &applicant, &validator, &None);
+    assert_eq!(result, Err(Ok(CrowdfundingError::ApplicationWithdrawn)));
+
+    let result = client.try_withdraw_application(&pool_id, &applicant);
+    assert_eq!(result, Err(Ok(CrowdfundingError::ApplicationWithdrawn)));
+}
+
+#[test]
+fn test_withdraw_missing_application_fails() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+
+    let result = client.try_withdraw_application(&pool_id, &applicant);
+    assert_eq!(result, Err(Ok(CrowdfundingError::ApplicationNotFound)));
+}
+
+#[test]
+fn test_application_stores_only_commitment_and_pointer() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let credentials = commitment(&env, b"transcript.pdf|id.png");
+    let pointer = Bytes::from_slice(
+        &env,
+        b"enc:bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &Some(pointer.clone()));
+
+    let application = client.get_application(&pool_id, &applicant);
+    assert_eq!(application.credentials_hash, credentials);
+    assert_eq!(application.encrypted_pointer, Some(pointer));
+    assert_eq!(application.submission_count, 1);
+}
+
+#[test]
+fn test_verify_credentials_matches_preimage() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let bundle = b"transcript.pdf|id.png";
+
+    client.apply_for_scholarship(&pool_id, &applicant, &commitment(&env, bundle), &None);
+
+    assert!(client.verify_credentials(&pool_id, &applicant, &Bytes::from_slice(&env, bundle)));
+    assert!(!client.verify_credentials(
+        &pool_id,
+        &applicant,
+        &Bytes::from_slice(&env, b"forged.pdf")
+    ));
+}
+
+#[test]
+fn test_verify_credentials_missing_application_fails() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+
+    let result = client.try_verify_credentials(&pool_id, &applicant, &Bytes::new(&env));
+    assert_eq!(result, Err(Ok(CrowdfundingError::ApplicationNotFound)));
+}
+
+#[test]
+fn test_apply_with_oversized_pointer_fails() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let pointer = Bytes::from_slice(&env, &[7u8; 257]);
+
+    let result = client.try_apply_for_scholarship(
+        &pool_id,
+        &applicant,
+        &commitment(&env, b"bundle"),
+        &Some(pointer),
+    );
+    assert_eq!(
+        result,
+        Err(Ok(CrowdfundingError::InvalidApplicationCredentials))
+    );
+}
+
+#[test]
+fn test_pending_application_cannot_be_resubmitted() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let credentials = commitment(&env, b"bundle");
+
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+
+    let result = client.try_apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    assert_eq!(
+        result,
+        Err(Ok(CrowdfundingError::ApplicationAlreadySubmitted))
+    );
+}
+
+#[test]
+fn test_rejected_applicant_can_resubmit() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let validator = client.get_pool(&pool_id).unwrap().validator.unwrap();
+
+    client.apply_for_scholarship(&pool_id, &applicant, &commitment(&env, b"v1"), &None);
+    client.reject_application(
+        &pool_id,
+        &applicant,
+        &validator,
+        &Some(String::from_str(&env, "Missing transcript")),
+    );
+
+    let updated = commitment(&env, b"v2");
+    client.apply_for_scholarship(&pool_id, &applicant, &updated, &None);
+
+    let application = client.get_application(&pool_id, &applicant);
+    assert_eq!(application.status, ApplicationStatus::Pending);
+    assert_eq!(application.credentials_hash, updated);
+    assert_eq!(application.submission_count, 2);
+    assert_eq!(application.reviewer, None);
+    assert_eq!(application.review_note, None);
+}
+
+#[test]
+fn test_resubmission_limit_is_enforced() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let validator = client.get_pool(&pool_id).unwrap().validator.unwrap();
+    let credentials = commitment(&env, b"bundle");
+
+    for _ in 0..MAX_APPLICATION_SUBMISSIONS {
+        client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+        client.reject_application(&pool_id, &applicant, &validator, &None);
+    }
+
+    let result = client.try_apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    assert_eq!(result, Err(Ok(CrowdfundingError::ResubmissionLimitReached)));
+}
+
+#[test]
+fn test_approved_applicant_cannot_resubmit() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let pool_id = create_pool(&env, &client, &token_address);
+    let applicant = Address::generate(&env);
+    let validator = client.get_pool(&pool_id).unwrap().validator.unwrap();
+    let credentials = commitment(&env, b"bundle");
+
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    client.approve_application(&pool_id, &applicant, &validator, &None);
+
+    let result = client.try_apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    assert_eq!(
+        result,
+        Err(Ok(CrowdfundingError::ApplicationAlreadySubmitted))
+    );
+}
+
+#[test]
+fn test_set_application_eligibility_requires_creator() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_pool_by(&env, &client, &token_address, &creator);
+    let outsider = Address::generate(&env);
+
+    let result = client.try_set_application_eligibility(&pool_id, &outsider, &open_rules());
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+
+    client.set_application_eligibility(&pool_id, &creator, &open_rules());
+    assert_eq!(
+        client.get_application_eligibility(&pool_id),
+        Some(open_rules())
+    );
+}
+
+#[test]
+fn test_set_application_eligibility_rejects_invalid_rules() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_pool_by(&env, &client, &token_address, &creator);
+
+    let mut rules = open_rules();
+    rules.window_start = Some(200);
+    rules.window_end = Some(100);
+    let result = client.try_set_application_eligibility(&pool_id, &creator, &rules);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidEligibilityRules)));
+
+    let mut rules = open_rules();
+    rules.max_applications = Some(0);
+    let result = client.try_set_application_eligibility(&pool_id, &creator, &rules);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidEligibilityRules)));
+
+    let mut allowlist = vec![&env];
+    for _ in 0..=MAX_ALLOWLIST_LENGTH {
+        allowlist.push_back(Address::generate(&env));
+    }
+    let mut rules = open_rules();
+    rules.allowlist = Some(allowlist);
+    let result = client.try_set_application_eligibility(&pool_id, &creator, &rules);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidEligibilityRules)));
+}
+
+#[test]
+fn test_apply_outside_window_fails() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_pool_by(&env, &client, &token_address, &creator);
+    let applicant = Address::generate(&env);
+    let credentials = commitment(&env, b"bundle");
+
+    let mut rules = open_rules();
+    rules.window_start = Some(1_000);
+    rules.window_end = Some(2_000);
+    client.set_application_eligibility(&pool_id, &creator, &rules);
+
+    env.ledger().set_timestamp(500);
+    let result = client.try_apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    assert_eq!(result, Err(Ok(CrowdfundingError::ApplicationWindowClosed)));
+
+    env.ledger().set_timestamp(2_000);
+    let result = client.try_apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    assert_eq!(result, Err(Ok(CrowdfundingError::ApplicationWindowClosed)));
+
+    env.ledger().set_timestamp(1_500);
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+}
+
+#[test]
+fn test_application_limit_counts_distinct_applicants() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_pool_by(&env, &client, &token_address, &creator);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let credentials = commitment(&env, b"bundle");
+
+    let mut rules = open_rules();
+    rules.max_applications = Some(1);
+    client.set_application_eligibility(&pool_id, &creator, &rules);
+
+    client.apply_for_scholarship(&pool_id, &first, &credentials, &None);
+    let result = client.try_apply_for_scholarship(&pool_id, &second, &credentials, &None);
+    assert_eq!(result, Err(Ok(CrowdfundingError::ApplicationLimitReached)));
+
+    // A resubmission does not take another slot
+    client.reject_application(&pool_id, &first, &creator, &None);
+    client.apply_for_scholarship(&pool_id, &first, &credentials, &None);
+}
+
+#[test]
+fn test_allowlist_restricts_applicants() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_pool_by(&env, &client, &token_address, &creator);
+    let listed = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let credentials = commitment(&env, b"bundle");
+
+    let mut rules = open_rules();
+    rules.allowlist = Some(vec![&env, listed.clone()]);
+    client.set_application_eligibility(&pool_id, &creator, &rules);
+
+    let result = client.try_apply_for_scholarship(&pool_id, &outsider, &credentials, &None);
+    assert_eq!(result, Err(Ok(CrowdfundingError::ApplicantNotEligible)));
+
+    client.apply_for_scholarship(&pool_id, &listed, &credentials, &None);
+}
+
+#[test]
+fn test_required_attestation_from_verified_issuer() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_pool_by(&env, &client, &token_address, &creator);
+    let issuer = Address::generate(&env);
+    let applicant = Address::generate(&env);
+    let credentials = commitment(&env, b"bundle");
+
+    let result = client.try_attest_applicant(&issuer, &applicant);
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+
+    client.verify_cause(&issuer);
+
+    let mut rules = open_rules();
+    rules.required_attester = Some(issuer.clone());
+    client.set_application_eligibility(&pool_id, &creator, &rules);
+
+    let result = client.try_apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    assert_eq!(result, Err(Ok(CrowdfundingError::ApplicantNotEligible)));
+
+    client.attest_applicant(&issuer, &applicant);
+    client.apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+}
+
+#[test]
+fn test_attestation_lapses_when_issuer_unverified() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_pool_by(&env, &client, &token_address, &creator);
+    let issuer = Address::generate(&env);
+    let applicant = Address::generate(&env);
+    let credentials = commitment(&env, b"bundle");
+
+    client.verify_cause(&issuer);
+    client.attest_applicant(&issuer, &applicant);
+
+    let mut rules = open_rules();
+    rules.required_attester = Some(issuer.clone());
+    client.set_application_eligibility(&pool_id, &creator, &rules);
 
-    let result = client.try_apply_for_scholarship(&pool_id, &applicant, &credentials);
-    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidApplicationCredentials)));
+    client.reject_cause(&issuer);
+    let result = client.try_apply_for_scholarship(&pool_id, &applicant, &credentials, &None);
+    assert_eq!(result, Err(Ok(CrowdfundingError::ApplicantNotEligible)));
 }
@@ -39,7 +39,9 @@ fn create_pool(client: &CrowdfundingContractClient<'_>, env: &Env, token: &Addre
         duration: 86_400,
         created_at: env.ledger().timestamp(),
         token_address: token.clone(),
+        validator: Some(creator.clone()),
     };
+    token::StellarAssetClient::new(env, token).mint(&creator, &config.target_amount);
     client.create_pool(&creator, &config)
 }
 
@@ -284,6 +286,9 @@ fn test_buy_ticket_accumulates_across_multiple_purchases() {
     client.set_platform_fee_bps(&250); // 2.5%
 
     let price = 10_000i128;
+    // The sponsor deposit is already held alongside ticket revenue
+    let token_client = token::Client::new(&env, &token);
+    let deposit = token_client.balance(&client.address);
 
     // Three separate buyers
     for _ in 0..3 {
@@ -292,10 +297,9 @@ fn test_buy_ticket_accumulates_across_multiple_purchases() {
 
     // Each ticket: fee = 250, event = 9_750
     // After 3 tickets: event pool = 29_250, platform fees = 750
-    let token_client = token::Client::new(&env, &token);
     let contract_balance = token_client.balance(&client.address);
     assert_eq!(
-        contract_balance,
+        contract_balance - deposit,
         price * 3,
         "contract holds all ticket revenue"
     );
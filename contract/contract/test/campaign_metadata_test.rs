@@ -0,0 +1,133 @@
+#![cfg(test)]
+
+use crate::{
+    base::{errors::CrowdfundingError, types::CampaignMetadata},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, String, Symbol,
+};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    (client, token)
+}
+
+fn metadata(env: &Env, category: Symbol) -> CampaignMetadata {
+    CampaignMetadata {
+        description: String::from_str(env, "Books for the village school"),
+        category,
+        external_url: String::from_str(env, "https://nevo.example/books"),
+        image_hash: String::from_str(env, "QmBooks"),
+    }
+}
+
+fn create(
+    env: &Env,
+    client: &CrowdfundingContractClient<'_>,
+    token: &Address,
+    creator: &Address,
+    seed: u8,
+    category: Symbol,
+) -> BytesN<32> {
+    let id = BytesN::from_array(env, &[seed; 32]);
+    client.create_campaign_with_metadata(
+        &id,
+        &String::from_str(env, "Campaign"),
+        creator,
+        &1_000,
+        &10_000,
+        token,
+        &metadata(env, category),
+    );
+    id
+}
+
+#[test]
+fn test_metadata_is_stored_at_creation() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+
+    let id = create(&env, &client, &token, &creator, 1, symbol_short!("edu"));
+
+    assert_eq!(
+        client.get_campaign_metadata(&id),
+        metadata(&env, symbol_short!("edu"))
+    );
+    assert_eq!(client.get_campaign(&id).creator, creator);
+}
+
+#[test]
+fn test_category_listing_follows_updates() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+
+    let first = create(&env, &client, &token, &creator, 1, symbol_short!("edu"));
+    let second = create(&env, &client, &token, &creator, 2, symbol_short!("edu"));
+    create(&env, &client, &token, &creator, 3, symbol_short!("health"));
+
+    let edu = client.list_campaigns_by_category(&symbol_short!("edu"), &0, &10);
+    assert_eq!(edu.len(), 2);
+    let page = client.list_campaigns_by_category(&symbol_short!("edu"), &1, &10);
+    assert_eq!(page.get(0).unwrap(), second);
+
+    client.update_campaign_metadata(&first, &creator, &metadata(&env, symbol_short!("health")));
+
+    let edu = client.list_campaigns_by_category(&symbol_short!("edu"), &0, &10);
+    assert_eq!(edu.len(), 1);
+    let health = client.list_campaigns_by_category(&symbol_short!("health"), &0, &10);
+    assert_eq!(health.len(), 2);
+    assert_eq!(health.get(1).unwrap(), first);
+}
+
+#[test]
+fn test_metadata_updates_are_creator_only_and_validated() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let id = create(&env, &client, &token, &creator, 1, symbol_short!("edu"));
+
+    let result =
+        client.try_update_campaign_metadata(&id, &outsider, &metadata(&env, symbol_short!("x")));
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+
+    let mut invalid = metadata(&env, symbol_short!("edu"));
+    invalid.image_hash = String::from_str(&env, &"Q".repeat(101));
+    let result = client.try_update_campaign_metadata(&id, &creator, &invalid);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidMetadata)));
+}
+
+#[test]
+fn test_campaign_without_metadata() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let id = BytesN::from_array(&env, &[7; 32]);
+    client.create_campaign(
+        &id,
+        &String::from_str(&env, "Plain"),
+        &creator,
+        &1_000,
+        &10_000,
+        &token,
+    );
+
+    let result = client.try_get_campaign_metadata(&id);
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignMetadataNotFound)));
+}
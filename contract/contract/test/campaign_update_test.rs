@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use crate::{
+    base::errors::CrowdfundingError,
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, String,
+};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address, BytesN<32>) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    let creator = Address::generate(env);
+    let campaign_id = BytesN::from_array(env, &[5; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Progress"),
+        &creator,
+        &1_000,
+        &10_000,
+        &token,
+    );
+
+    (client, creator, campaign_id)
+}
+
+#[test]
+fn test_updates_are_appended_in_order_and_paginated() {
+    let env = Env::default();
+    let (client, creator, campaign_id) = setup(&env);
+
+    for i in 0..3u8 {
+        env.ledger().set_timestamp(1_000 + i as u64);
+        let index = client.post_campaign_update(
+            &campaign_id,
+            &creator,
+            &BytesN::from_array(&env, &[i; 32]),
+            &String::from_str(&env, "ipfs://report"),
+        );
+        assert_eq!(index, i as u32);
+    }
+
+    let page = client.get_campaign_updates(&campaign_id, &1, &5);
+    assert_eq!(page.len(), 2);
+    let update = page.get(0).unwrap();
+    assert_eq!(update.index, 1);
+    assert_eq!(update.content_hash, BytesN::from_array(&env, &[1; 32]));
+    assert_eq!(update.posted_at, 1_001);
+
+    assert_eq!(client.get_campaign_updates(&campaign_id, &3, &5).len(), 0);
+}
+
+#[test]
+fn test_only_creator_can_post_updates() {
+    let env = Env::default();
+    let (client, _, campaign_id) = setup(&env);
+    let outsider = Address::generate(&env);
+
+    let result = client.try_post_campaign_update(
+        &campaign_id,
+        &outsider,
+        &BytesN::from_array(&env, &[9; 32]),
+        &String::from_str(&env, "ipfs://spam"),
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+    assert_eq!(client.get_campaign_updates(&campaign_id, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_updates_for_unknown_campaign_fail() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    let result = client.try_get_campaign_updates(&BytesN::from_array(&env, &[0; 32]), &0, &10);
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignNotFound)));
+}
@@ -1,6 +1,10 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, Env, String,
+};
 
 use crate::{
     base::{
@@ -40,12 +44,32 @@ fn create_test_pool(
         duration: 86400, // 1 day
         created_at: env.ledger().timestamp(),
         token_address: token_address.clone(),
-        validator: creator.clone(), // For simplicity, creator is also validator
+        validator: Some(creator.clone()), // For simplicity, creator is also validator
     };
 
+    StellarAssetClient::new(env, token_address).mint(creator, &config.target_amount);
     client.create_pool(creator, &config)
 }
 
+// Pays the pool out to a verified cause, moving it to Disbursed.
+fn disburse_pool(
+    client: &CrowdfundingContractClient,
+    env: &Env,
+    pool_id: u64,
+    token_address: &Address,
+) {
+    let student = Address::generate(env);
+    client.verify_cause(&student);
+    StellarAssetClient::new(env, token_address).mint(&student, &1);
+    client.contribute(&pool_id, &student, token_address, &1, &false);
+    client.claim_pool_funds(&pool_id, &student);
+}
+
+// Returns the sponsor's deposit, moving the pool to Cancelled.
+fn cancel_pool(client: &CrowdfundingContractClient, pool_id: u64, creator: &Address) {
+    client.deactivate_pool(&pool_id, creator);
+}
+
 #[test]
 fn test_close_pool_success_after_disbursement() {
     let env = Env::default();
@@ -54,8 +78,8 @@ fn test_close_pool_success_after_disbursement() {
     let creator = Address::generate(&env);
     let pool_id = create_test_pool(&client, &env, &creator, &token_address);
 
-    // Update pool state to Disbursed
-    client.update_pool_state(&pool_id, &creator, &PoolState::Disbursed);
+    // Pay the pool out
+    disburse_pool(&client, &env, pool_id, &token_address);
 
     // Close the pool as admin
     client.close_pool(&pool_id, &admin);
@@ -73,8 +97,8 @@ fn test_close_pool_success_after_cancellation() {
     let creator = Address::generate(&env);
     let pool_id = create_test_pool(&client, &env, &creator, &token_address);
 
-    // Update pool state to Cancelled
-    client.update_pool_state(&pool_id, &creator, &PoolState::Cancelled);
+    // Cancel the pool
+    cancel_pool(&client, pool_id, &creator);
 
     // Close the pool as admin
     client.close_pool(&pool_id, &admin);
@@ -92,8 +116,8 @@ fn test_close_pool_already_closed() {
     let creator = Address::generate(&env);
     let pool_id = create_test_pool(&client, &env, &creator, &token_address);
 
-    // Update pool state to Disbursed
-    client.update_pool_state(&pool_id, &creator, &PoolState::Disbursed);
+    // Pay the pool out
+    disburse_pool(&client, &env, pool_id, &token_address);
 
     // Close the pool
     client.close_pool(&pool_id, &admin);
@@ -146,7 +170,8 @@ fn test_close_pool_completed_state() {
     let creator = Address::generate(&env);
     let pool_id = create_test_pool(&client, &env, &creator, &token_address);
 
-    // Update pool state to Completed
+    // Complete the pool once its deadline has passed
+    env.ledger().set_timestamp(86400);
     client.update_pool_state(&pool_id, &creator, &PoolState::Completed);
 
     // Try to close - should fail
@@ -176,8 +201,8 @@ fn test_close_pool_unauthorized() {
     let creator = Address::generate(&env);
     let pool_id = create_test_pool(&client, &env, &creator, &token_address);
 
-    // Update pool state to Disbursed
-    client.update_pool_state(&pool_id, &creator, &PoolState::Disbursed);
+    // Pay the pool out
+    disburse_pool(&client, &env, pool_id, &token_address);
 
     // Try to close as non-admin
     let unauthorized_user = Address::generate(&env);
@@ -205,8 +230,8 @@ fn test_is_closed_for_closed_pool() {
     let creator = Address::generate(&env);
     let pool_id = create_test_pool(&client, &env, &creator, &token_address);
 
-    // Update to Disbursed and close
-    client.update_pool_state(&pool_id, &creator, &PoolState::Disbursed);
+    // Pay out and close
+    disburse_pool(&client, &env, pool_id, &token_address);
     client.close_pool(&pool_id, &admin);
 
     let is_closed = client.is_closed(&pool_id);
@@ -232,8 +257,8 @@ fn test_close_pool_emits_event() {
     let creator = Address::generate(&env);
     let pool_id = create_test_pool(&client, &env, &creator, &token_address);
 
-    // Update pool state to Disbursed
-    client.update_pool_state(&pool_id, &creator, &PoolState::Disbursed);
+    // Pay the pool out
+    disburse_pool(&client, &env, pool_id, &token_address);
 
     // Close the pool
     client.close_pool(&pool_id, &admin);
@@ -257,9 +282,9 @@ fn test_close_pool_multiple_pools() {
     let pool_id_3 = create_test_pool(&client, &env, &creator, &token_address);
 
     // Update states
-    client.update_pool_state(&pool_id_1, &creator, &PoolState::Disbursed);
-    client.update_pool_state(&pool_id_2, &creator, &PoolState::Cancelled);
-    client.update_pool_state(&pool_id_3, &creator, &PoolState::Disbursed);
+    disburse_pool(&client, &env, pool_id_1, &token_address);
+    cancel_pool(&client, pool_id_2, &creator);
+    disburse_pool(&client, &env, pool_id_3, &token_address);
 
     // Close pools 1 and 3
     client.close_pool(&pool_id_1, &admin);
@@ -289,8 +314,8 @@ fn test_close_pool_state_transition_sequence() {
         Err(Ok(CrowdfundingError::PoolNotDisbursedOrRefunded))
     );
 
-    // Transition to Disbursed
-    client.update_pool_state(&pool_id, &creator, &PoolState::Disbursed);
+    // Pay the pool out
+    disburse_pool(&client, &env, pool_id, &token_address);
     assert!(!client.is_closed(&pool_id));
 
     // Now close should succeed
@@ -306,8 +331,8 @@ fn test_close_pool_after_refund_scenario() {
     let creator = Address::generate(&env);
     let pool_id = create_test_pool(&client, &env, &creator, &token_address);
 
-    // Simulate refund scenario by setting state to Cancelled
-    client.update_pool_state(&pool_id, &creator, &PoolState::Cancelled);
+    // Cancel the pool, returning the sponsor's deposit
+    cancel_pool(&client, pool_id, &creator);
 
     // Close the pool
     client.close_pool(&pool_id, &admin);
@@ -333,11 +358,12 @@ fn test_is_closed_for_different_states() {
 
     // Set states
     client.update_pool_state(&pool_paused, &creator, &PoolState::Paused);
-    client.update_pool_state(&pool_completed, &creator, &PoolState::Completed);
-    client.update_pool_state(&pool_cancelled, &creator, &PoolState::Cancelled);
-    client.update_pool_state(&pool_disbursed, &creator, &PoolState::Disbursed);
-    client.update_pool_state(&pool_closed, &creator, &PoolState::Disbursed);
+    cancel_pool(&client, pool_cancelled, &creator);
+    disburse_pool(&client, &env, pool_disbursed, &token_address);
+    disburse_pool(&client, &env, pool_closed, &token_address);
     client.close_pool(&pool_closed, &admin);
+    env.ledger().set_timestamp(86400);
+    client.update_pool_state(&pool_completed, &creator, &PoolState::Completed);
 
     // Verify is_closed returns false for all except Closed state
     assert!(!client.is_closed(&pool_active));
@@ -347,3 +373,88 @@ fn test_is_closed_for_different_states() {
     assert!(!client.is_closed(&pool_disbursed));
     assert!(client.is_closed(&pool_closed));
 }
+
+#[test]
+fn test_update_pool_state_cannot_skip_payout() {
+    let env = Env::default();
+    let (client, _admin, token_address) = setup_test(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_test_pool(&client, &env, &creator, &token_address);
+
+    // Disbursing and closing only happen through the flows that move funds
+    for state in [
+        PoolState::Disbursed,
+        PoolState::Closed,
+        PoolState::Cancelled,
+    ] {
+        let result = client.try_update_pool_state(&pool_id, &creator, &state);
+        assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolState)));
+    }
+
+    // Completing waits for the deadline
+    let result = client.try_update_pool_state(&pool_id, &creator, &PoolState::Completed);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolNotExpired)));
+}
+
+#[test]
+fn test_get_allowed_transitions_depends_on_caller() {
+    let env = Env::default();
+    let (client, admin, token_address) = setup_test(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_test_pool(&client, &env, &creator, &token_address);
+
+    let allowed = client.get_allowed_transitions(&pool_id, &creator);
+    assert_eq!(allowed.len(), 2);
+    assert!(allowed.contains(PoolState::Paused));
+    assert!(allowed.contains(PoolState::Cancelled));
+    assert_eq!(client.get_allowed_transitions(&pool_id, &admin).len(), 0);
+
+    env.ledger().set_timestamp(86400);
+    assert!(client
+        .get_allowed_transitions(&pool_id, &creator)
+        .contains(PoolState::Completed));
+
+    cancel_pool(&client, pool_id, &creator);
+    let allowed = client.get_allowed_transitions(&pool_id, &admin);
+    assert_eq!(allowed.len(), 1);
+    assert!(allowed.contains(PoolState::Closed));
+}
+
+#[test]
+fn test_validator_can_cancel_and_deposit_returns_to_sponsor() {
+    let env = Env::default();
+    let (client, _admin, token_address) = setup_test(&env);
+
+    let creator = Address::generate(&env);
+    let validator = Address::generate(&env);
+    let config = PoolConfig {
+        name: String::from_str(&env, "Validated Pool"),
+        description: String::from_str(&env, "A pool with its own validator"),
+        target_amount: 1_000,
+        min_contribution: 0,
+        is_private: false,
+        duration: 86400,
+        created_at: env.ledger().timestamp(),
+        token_address: token_address.clone(),
+        validator: Some(validator.clone()),
+    };
+    StellarAssetClient::new(&env, &token_address).mint(&creator, &config.target_amount);
+    let pool_id = client.create_pool(&creator, &config);
+
+    // Anyone else is turned away by cancel_pool itself
+    let reason = String::from_str(&env, "");
+    let stranger = Address::generate(&env);
+    let result = client.try_cancel_pool(&pool_id, &stranger, &reason);
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+
+    assert!(client
+        .get_allowed_transitions(&pool_id, &validator)
+        .contains(PoolState::Cancelled));
+    assert_eq!(client.cancel_pool(&pool_id, &validator, &reason), 1_000);
+
+    let token = TokenClient::new(&env, &token_address);
+    assert_eq!(token.balance(&creator), 1_000);
+    assert_eq!(token.balance(&validator), 0);
+}
@@ -1,6 +1,10 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, String,
+};
 
 use crate::{
     base::{
@@ -40,7 +44,9 @@ fn create_private_pool(
         duration: 86400,
         created_at: env.ledger().timestamp(),
         token_address: token_address.clone(),
+        validator: Some(creator.clone()),
     };
+    StellarAssetClient::new(env, token_address).mint(creator, &config.target_amount);
     client.create_pool(creator, &config)
 }
 
@@ -59,10 +65,26 @@ fn create_public_pool(
         duration: 86400,
         created_at: env.ledger().timestamp(),
         token_address: token_address.clone(),
+        validator: Some(creator.clone()),
     };
+    StellarAssetClient::new(env, token_address).mint(creator, &config.target_amount);
     client.create_pool(creator, &config)
 }
 
+// Pays the pool out to a verified cause, moving it to Disbursed.
+fn disburse_pool(
+    client: &CrowdfundingContractClient,
+    env: &Env,
+    pool_id: u64,
+    token_address: &Address,
+) {
+    let student = Address::generate(env);
+    client.verify_cause(&student);
+    StellarAssetClient::new(env, token_address).mint(&student, &100);
+    client.contribute(&pool_id, &student, token_address, &100, &false);
+    client.claim_pool_funds(&pool_id, &student);
+}
+
 #[test]
 fn test_owner_can_close_private_pool() {
     let env = Env::default();
@@ -143,7 +165,7 @@ fn test_owner_can_close_paused_private_pool() {
     let owner = Address::generate(&env);
     let pool_id = create_private_pool(&client, &env, &owner, &token_address);
 
-    client.update_pool_state(&pool_id, &PoolState::Paused);
+    client.update_pool_state(&pool_id, &owner, &PoolState::Paused);
     client.close_pool(&pool_id, &owner);
 
     let is_closed = client.is_closed(&pool_id);
@@ -158,7 +180,9 @@ fn test_owner_cannot_close_completed_private_pool() {
     let owner = Address::generate(&env);
     let pool_id = create_private_pool(&client, &env, &owner, &token_address);
 
-    client.update_pool_state(&pool_id, &PoolState::Completed);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 86_401);
+    client.update_pool_state(&pool_id, &owner, &PoolState::Completed);
 
     let result = client.try_close_pool(&pool_id, &owner);
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolState)));
@@ -235,7 +259,7 @@ fn test_admin_can_close_after_disbursement() {
     let owner = Address::generate(&env);
     let pool_id = create_private_pool(&client, &env, &owner, &token_address);
 
-    client.update_pool_state(&pool_id, &PoolState::Disbursed);
+    disburse_pool(&client, &env, pool_id, &token_address);
     client.close_pool(&pool_id, &admin);
 
     assert!(client.is_closed(&pool_id));
@@ -249,7 +273,7 @@ fn test_owner_can_close_after_cancellation() {
     let owner = Address::generate(&env);
     let pool_id = create_private_pool(&client, &env, &owner, &token_address);
 
-    client.update_pool_state(&pool_id, &PoolState::Cancelled);
+    client.deactivate_pool(&pool_id, &owner);
     client.close_pool(&pool_id, &owner);
 
     assert!(client.is_closed(&pool_id));
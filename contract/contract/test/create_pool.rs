@@ -49,7 +49,7 @@ fn test_create_pool_success() {
         duration: 30 * 24 * 60 * 60,
         created_at: env.ledger().timestamp(),
         token_address: token_address.clone(),
-        validator: creator.clone(),
+        validator: Some(creator.clone()),
     };
 
     mint(&env, &token_address, &creator, config.target_amount);
@@ -84,7 +84,7 @@ fn test_create_pool_invalid_token_fails() {
         duration: 86400,
         created_at: env.ledger().timestamp(),
         token_address: wrong_token,
-        validator: creator.clone(),
+        validator: Some(creator.clone()),
     };
 
     let result = client.try_create_pool(&creator, &config);
@@ -92,8 +92,7 @@ fn test_create_pool_invalid_token_fails() {
 }
 
 #[test]
-#[should_panic(expected = "description too long")]
-fn test_create_pool_panic_description_length() {
+fn test_create_pool_rejects_long_description() {
     let env = Env::default();
     let (client, _, token_address) = setup(&env);
 
@@ -109,10 +108,13 @@ fn test_create_pool_panic_description_length() {
         duration: 86400,
         created_at: env.ledger().timestamp(),
         token_address,
-        validator: creator.clone(),
+        validator: Some(creator.clone()),
     };
 
-    client.create_pool(&creator, &config);
+    assert_eq!(
+        client.try_create_pool(&creator, &config),
+        Err(Ok(CrowdfundingError::DescriptionTooLong))
+    );
 }
 
 #[test]
@@ -132,6 +134,7 @@ fn test_create_pool_invalid_description_length() {
         duration: 86400,
         created_at: env.ledger().timestamp(),
         token_address,
+        validator: Some(creator.clone()),
     };
 
     let _result = client.try_create_pool(&creator, &config);
@@ -155,6 +158,7 @@ fn test_create_pool_validation_logic() {
         duration: 86400,
         created_at: env.ledger().timestamp(),
         token_address,
+        validator: Some(creator.clone()),
     };
 
     let result = client.try_create_pool(&creator, &config);
@@ -192,6 +196,7 @@ fn test_create_pool_emits_event_created() {
         duration,
         created_at,
         token_address: token_address.clone(),
+        validator: Some(creator.clone()),
     };
 
     mint(&env, &token_address, &creator, target_amount);
@@ -8,7 +8,7 @@ use soroban_sdk::{
 use crate::{
     base::{
         errors::CrowdfundingError,
-        types::{PoolMetadata, PoolState},
+        types::{PoolConfig, PoolMetadata, PoolState},
     },
     crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
 };
@@ -437,10 +437,7 @@ fn test_get_campaign_fee_history() {
 #[test]
 fn test_save_pool() {
     let env = Env::default();
-    env.mock_all_auths();
-
-    let contract_id = env.register(CrowdfundingContract, ());
-    let client = CrowdfundingContractClient::new(&env, &contract_id);
+    let (client, _, _) = setup_test(&env);
 
     let creator = Address::generate(&env);
     let name = String::from_str(&env, "Education Fund");
@@ -526,10 +523,7 @@ fn test_save_pool_validation() {
 #[test]
 fn test_get_pool() {
     let env = Env::default();
-    env.mock_all_auths();
-
-    let contract_id = env.register(CrowdfundingContract, ());
-    let client = CrowdfundingContractClient::new(&env, &contract_id);
+    let (client, _, _) = setup_test(&env);
 
     let creator = Address::generate(&env);
     let name = String::from_str(&env, "Medical Fund");
@@ -595,10 +589,7 @@ fn test_get_nonexistent_pool() {
 #[test]
 fn test_update_pool_state() {
     let env = Env::default();
-    env.mock_all_auths();
-
-    let contract_id = env.register(CrowdfundingContract, ());
-    let client = CrowdfundingContractClient::new(&env, &contract_id);
+    let (client, _, _) = setup_test(&env);
 
     let creator = Address::generate(&env);
     let name = String::from_str(&env, "Charity Fund");
@@ -623,7 +614,8 @@ fn test_update_pool_state() {
     // Update state to Paused
     client.update_pool_state(&pool_id, &creator, &PoolState::Paused);
 
-    // Update state to Completed
+    // Update state to Completed once the deadline has passed
+    env.ledger().set_timestamp(deadline + 1);
     client.update_pool_state(&pool_id, &creator, &PoolState::Completed);
 }
 
@@ -635,17 +627,15 @@ fn test_update_pool_state_nonexistent() {
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
 
-    let result = client.try_update_pool_state(&999, &PoolState::Paused);
+    let caller = Address::generate(&env);
+    let result = client.try_update_pool_state(&999, &caller, &PoolState::Paused);
     assert_eq!(result, Err(Ok(CrowdfundingError::PoolNotFound)));
 }
 
 #[test]
 fn test_update_pool_state_invalid_transition() {
     let env = Env::default();
-    env.mock_all_auths();
-
-    let contract_id = env.register(CrowdfundingContract, ());
-    let client = CrowdfundingContractClient::new(&env, &contract_id);
+    let (client, _, _) = setup_test(&env);
 
     let creator = Address::generate(&env);
     let name = String::from_str(&env, "Test Fund");
@@ -668,6 +658,7 @@ fn test_update_pool_state_invalid_transition() {
     );
 
     // First complete the pool
+    env.ledger().set_timestamp(deadline + 1);
     client.update_pool_state(&pool_id, &creator, &PoolState::Completed);
 
     // Try to change state from completed - should fail
@@ -681,10 +672,7 @@ fn test_update_pool_state_invalid_transition() {
 #[test]
 fn test_multiple_pools() {
     let env = Env::default();
-    env.mock_all_auths();
-
-    let contract_id = env.register(CrowdfundingContract, ());
-    let client = CrowdfundingContractClient::new(&env, &contract_id);
+    let (client, _, _) = setup_test(&env);
 
     let creator1 = Address::generate(&env);
     let creator2 = Address::generate(&env);
@@ -741,8 +729,9 @@ fn test_multiple_pools() {
     assert_eq!(pool2.target_amount, target2);
 
     // Update different states
-    client.update_pool_state(&pool_id1, &PoolState::Paused);
-    client.update_pool_state(&pool_id2, &PoolState::Active);
+    client.update_pool_state(&pool_id1, &creator1, &PoolState::Paused);
+    client.update_pool_state(&pool_id2, &creator2, &PoolState::Paused);
+    client.update_pool_state(&pool_id2, &creator2, &PoolState::Active);
 }
 
 #[test]
@@ -1043,6 +1032,7 @@ fn test_contribute_and_event_emission() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2075,6 +2065,7 @@ fn test_refund_after_deadline_and_grace_period() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     // Create pool with deadline
     let creator = Address::generate(&env);
@@ -2143,6 +2134,7 @@ fn test_refund_partial_contribution() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     // Create pool
     let creator = Address::generate(&env);
@@ -2222,6 +2214,7 @@ fn test_refund_fails_before_deadline() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2271,6 +2264,7 @@ fn test_refund_fails_before_grace_period() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2328,6 +2322,7 @@ fn test_refund_fails_if_disbursed() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2361,8 +2356,12 @@ fn test_refund_fails_if_disbursed() {
         &false,
     );
 
-    // Mark pool as disbursed
-    client.update_pool_state(&pool_id, &PoolState::Disbursed);
+    // Pay the pool out to a verified cause
+    let student = Address::generate(&env);
+    client.verify_cause(&student);
+    token_admin_client.mint(&student, &1i128);
+    client.contribute(&pool_id, &student, &token_id.address(), &1i128, &false);
+    client.claim_pool_funds(&pool_id, &student);
 
     // Advance time past deadline + grace period
     let grace_period = 604800u64;
@@ -2406,10 +2405,7 @@ fn test_create_campaign_insufficient_balance() {
 #[test]
 fn test_refund_fails_no_contribution() {
     let env = Env::default();
-    env.mock_all_auths();
-
-    let contract_id = env.register(CrowdfundingContract, ());
-    let client = CrowdfundingContractClient::new(&env, &contract_id);
+    let (client, _, _) = setup_test(&env);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2456,6 +2452,7 @@ fn test_multiple_contributors_refund_independently() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor1 = Address::generate(&env);
@@ -2529,6 +2526,7 @@ fn test_refund_fails_after_already_refunded() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2577,6 +2575,16 @@ fn test_refund_fails_after_already_refunded() {
 
 // Emergency Withdrawal Tests
 
+fn allow_emergency_safe_address(
+    env: &Env,
+    client: &CrowdfundingContractClient<'_>,
+    address: &Address,
+) {
+    client.set_emergency_safe_address(address, &true);
+    env.ledger().with_mut(|li| li.timestamp += 86400);
+    client.execute_emergency_safe_address(address);
+}
+
 #[test]
 fn test_request_emergency_withdraw_success() {
     let env = Env::default();
@@ -2591,9 +2599,10 @@ fn test_request_emergency_withdraw_success() {
     let token_address = token_contract.address();
 
     client.initialize(&admin, &token_address, &0);
+    allow_emergency_safe_address(&env, &client, &admin);
 
     let amount = 1_000i128;
-    client.request_emergency_withdraw(&token_address, &amount);
+    client.request_emergency_withdraw(&token_address, &amount, &admin);
 }
 
 #[test]
@@ -2610,11 +2619,12 @@ fn test_request_emergency_withdraw_duplicate() {
     let token_address = token_contract.address();
 
     client.initialize(&admin, &token_address, &0);
+    allow_emergency_safe_address(&env, &client, &admin);
 
     let amount = 1_000i128;
-    client.request_emergency_withdraw(&token_address, &amount);
+    client.request_emergency_withdraw(&token_address, &amount, &admin);
 
-    let result = client.try_request_emergency_withdraw(&token_address, &amount);
+    let result = client.try_request_emergency_withdraw(&token_address, &amount, &admin);
     assert_eq!(
         result,
         Err(Ok(CrowdfundingError::EmergencyWithdrawalAlreadyRequested))
@@ -2637,14 +2647,14 @@ fn test_execute_emergency_withdraw_success() {
     let token_client = token::Client::new(&env, &token_address);
 
     client.initialize(&admin, &token_address, &0);
+    allow_emergency_safe_address(&env, &client, &admin);
 
     token_admin_client.mint(&contract_id, &5_000i128);
 
     let amount = 1_000i128;
-    let now = 1000u64;
-    env.ledger().with_mut(|li| li.timestamp = now);
+    let now = env.ledger().timestamp();
 
-    client.request_emergency_withdraw(&token_address, &amount);
+    client.request_emergency_withdraw(&token_address, &amount, &admin);
 
     env.ledger().with_mut(|li| li.timestamp = now + 86400 + 1);
 
@@ -2668,12 +2678,12 @@ fn test_execute_emergency_withdraw_before_grace_period() {
     let token_address = token_contract.address();
 
     client.initialize(&admin, &token_address, &0);
+    allow_emergency_safe_address(&env, &client, &admin);
 
     let amount = 1_000i128;
-    let now = 1000u64;
-    env.ledger().with_mut(|li| li.timestamp = now);
+    let now = env.ledger().timestamp();
 
-    client.request_emergency_withdraw(&token_address, &amount);
+    client.request_emergency_withdraw(&token_address, &amount, &admin);
 
     env.ledger().with_mut(|li| li.timestamp = now + 86399);
 
@@ -2720,6 +2730,7 @@ fn test_contribute_to_pool_success() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2813,6 +2824,7 @@ fn test_contribute_to_non_active_pool() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2835,7 +2847,7 @@ fn test_contribute_to_non_active_pool() {
         &None::<Vec<Address>>,
     );
 
-    client.update_pool_state(&pool_id, &PoolState::Completed);
+    client.update_pool_state(&pool_id, &creator, &PoolState::Paused);
 
     token_admin_client.mint(&contributor, &5_000i128);
 
@@ -2859,6 +2871,7 @@ fn test_contribute_zero_amount() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2919,6 +2932,7 @@ fn test_contribute_multiple_contributors() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor1 = Address::generate(&env);
@@ -2982,6 +2996,7 @@ fn test_contribute_private_contribution() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -3431,6 +3446,14 @@ fn test_withdraw_event_fees_success() {
         env.storage()
             .instance()
             .set(&crate::base::types::StorageKey::EventFeeTreasury, &500i128);
+        // Fees collected through buy_ticket are booked as a liability too
+        env.storage().instance().set(
+            &crate::base::types::SecondStorageKey::Liability(
+                token_address.clone(),
+                crate::base::types::LiabilityBucket::EventFees,
+            ),
+            &500i128,
+        );
     });
 
     let to = Address::generate(&env);
@@ -3533,6 +3556,10 @@ fn test_set_emergency_contact_updates_existing() {
 
     let contact2 = Address::generate(&env);
     client.set_emergency_contact(&contact2);
+    assert_eq!(client.get_emergency_contact(), contact1);
+
+    env.ledger().with_mut(|li| li.timestamp += 86400);
+    client.execute_emergency_contact_change();
 
     let stored_contact = client.get_emergency_contact();
     assert_eq!(stored_contact, contact2);
@@ -3559,10 +3586,14 @@ fn test_emergency_contact_multiple_updates() {
 
     let contact2 = Address::generate(&env);
     client.set_emergency_contact(&contact2);
+    env.ledger().with_mut(|li| li.timestamp += 86400);
+    client.execute_emergency_contact_change();
     assert_eq!(client.get_emergency_contact(), contact2);
 
     let contact3 = Address::generate(&env);
     client.set_emergency_contact(&contact3);
+    env.ledger().with_mut(|li| li.timestamp += 86400);
+    client.execute_emergency_contact_change();
     assert_eq!(client.get_emergency_contact(), contact3);
 }
 
@@ -3782,15 +3813,19 @@ fn test_update_pool_state_validator_authorization() {
         duration: 86400,
         created_at: env.ledger().timestamp(),
         token_address: token_contract.address(),
-        validator: validator.clone(),
+        validator: Some(validator.clone()),
     };
 
+    let token_admin_client = token::StellarAssetClient::new(&env, &token_contract.address());
+    token_admin_client.mint(&creator, &config.target_amount);
     let pool_id = client.create_pool(&creator, &config);
 
     // Creator should be able to update state
     client.update_pool_state(&pool_id, &creator, &PoolState::Paused);
 
     // Validator should be able to update state
+    env.ledger()
+        .set_timestamp(config.created_at + config.duration + 1);
     client.update_pool_state(&pool_id, &validator, &PoolState::Completed);
 
     // Random address should not be able to update state
@@ -3823,12 +3858,16 @@ fn test_update_pool_state_lock_mechanics() {
         duration: 86400,
         created_at: env.ledger().timestamp(),
         token_address: token_contract.address(),
-        validator: creator.clone(),
+        validator: Some(creator.clone()),
     };
 
+    let token_admin_client = token::StellarAssetClient::new(&env, &token_contract.address());
+    token_admin_client.mint(&creator, &config.target_amount);
     let pool_id = client.create_pool(&creator, &config);
 
     // Set to Completed
+    env.ledger()
+        .set_timestamp(config.created_at + config.duration + 1);
     client.update_pool_state(&pool_id, &creator, &PoolState::Completed);
 
     // Try to change from Completed - should fail
@@ -3841,8 +3880,15 @@ fn test_update_pool_state_lock_mechanics() {
     let result = client.try_update_pool_state(&pool_id, &creator, &PoolState::Cancelled);
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolState)));
 
-    // Set to Cancelled
-    client.update_pool_state(&pool_id, &creator, &PoolState::Cancelled);
+    // A completed pool cannot be cancelled either
+    let reason = String::from_str(&env, "");
+    let result = client.try_cancel_pool(&pool_id, &creator, &reason);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolState)));
+
+    // Cancel a second pool
+    token_admin_client.mint(&creator, &config.target_amount);
+    let pool_id = client.create_pool(&creator, &config);
+    client.cancel_pool(&pool_id, &creator, &reason);
 
     // Try to change from Cancelled - should fail
     let result = client.try_update_pool_state(&pool_id, &creator, &PoolState::Active);
@@ -3874,8 +3920,10 @@ fn test_validator_malicious_modification_prevention() {
         duration: 86400,
         created_at: env.ledger().timestamp(),
         token_address: token_contract.address(),
-        validator: validator1.clone(),
+        validator: Some(validator1.clone()),
     };
+    let token_admin_client = token::StellarAssetClient::new(&env, &token_contract.address());
+    token_admin_client.mint(&creator1, &config1.target_amount);
     let pool_id1 = client.create_pool(&creator1, &config1);
 
     let creator2 = Address::generate(&env);
@@ -3889,8 +3937,9 @@ fn test_validator_malicious_modification_prevention() {
         duration: 86400,
         created_at: env.ledger().timestamp(),
         token_address: token_contract.address(),
-        validator: validator2.clone(),
+        validator: Some(validator2.clone()),
     };
+    token_admin_client.mint(&creator2, &config2.target_amount);
     let pool_id2 = client.create_pool(&creator2, &config2);
 
     // validator1 should not be able to modify pool_id2
@@ -3909,5 +3958,7 @@ fn test_validator_malicious_modification_prevention() {
     client.update_pool_state(&pool_id1, &validator1, &PoolState::Paused);
 
     // And validator2 can modify pool_id2
+    env.ledger()
+        .set_timestamp(config2.created_at + config2.duration + 1);
     client.update_pool_state(&pool_id2, &validator2, &PoolState::Completed);
 }
@@ -0,0 +1,156 @@
+#![cfg(test)]
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        types::{PoolConfig, SecondStorageKey, PERSISTENT_BUMP_AMOUNT},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env, String,
+};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    (client, token)
+}
+
+fn create_pool(env: &Env, client: &CrowdfundingContractClient<'_>, token: &Address) -> u64 {
+    let creator = Address::generate(env);
+    let config = PoolConfig {
+        name: String::from_str(env, "Pool"),
+        description: String::from_str(env, "Ledger test"),
+        target_amount: 1_000,
+        min_contribution: 0,
+        is_private: false,
+        duration: 86_400,
+        created_at: env.ledger().timestamp(),
+        token_address: token.clone(),
+        validator: Some(creator.clone()),
+    };
+
+    StellarAssetClient::new(env, token).mint(&creator, &config.target_amount);
+    client.create_pool(&creator, &config)
+}
+
+#[test]
+fn test_campaign_ledger_keeps_every_gift() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let campaign_id = BytesN::from_array(&env, &[1; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(&env, "Ledger"),
+        &Address::generate(&env),
+        &10_000,
+        &10_000,
+        &token,
+    );
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&alice, &1_000);
+    StellarAssetClient::new(&env, &token).mint(&bob, &1_000);
+
+    client.donate(&campaign_id, &alice, &token, &100);
+    env.ledger().set_timestamp(2_000);
+    client.donate(&campaign_id, &bob, &token, &200);
+    let memo = String::from_str(&env, "In memory of Ada");
+    client.donate_with_memo(&campaign_id, &alice, &token, &300, &memo);
+
+    let all = client.get_campaign_donations(&campaign_id, &0, &10);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all.get(0).unwrap().timestamp, 1_000);
+    assert_eq!(all.get(1).unwrap().donor, bob);
+
+    let history = client.get_campaign_donor_history(&campaign_id, &alice, &0, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().amount, 100);
+    let last = history.get(1).unwrap();
+    assert_eq!(last.amount, 300);
+    assert_eq!(last.memo, Some(memo));
+
+    // Cumulative totals used for refunds are unchanged
+    assert_eq!(client.get_contribution(&campaign_id, &alice), 400);
+}
+
+#[test]
+fn test_pool_ledger_records_privacy_and_pages() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let pool_id = create_pool(&env, &client, &token);
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &1_000);
+
+    client.contribute(&pool_id, &donor, &token, &100, &false);
+    client.contribute_with_memo(
+        &pool_id,
+        &donor,
+        &token,
+        &200,
+        &true,
+        &String::from_str(&env, "anonymous"),
+    );
+
+    let page = client.get_pool_donations(&pool_id, &1, &5);
+    assert_eq!(page.len(), 1);
+    assert!(page.get(0).unwrap().is_private);
+    assert_eq!(
+        client
+            .get_pool_donor_history(&pool_id, &donor, &0, &5)
+            .len(),
+        2
+    );
+    assert_eq!(client.get_pool_donations(&pool_id, &2, &5).len(), 0);
+}
+
+#[test]
+fn test_memo_length_is_limited() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let pool_id = create_pool(&env, &client, &token);
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &100);
+
+    let memo = String::from_str(&env, &"m".repeat(141));
+    let result = client.try_contribute_with_memo(&pool_id, &donor, &token, &100, &false, &memo);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidMemo)));
+
+    let result = client.try_get_pool_donations(&99, &0, &5);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolNotFound)));
+}
+
+#[test]
+fn test_ledger_entries_are_kept_alive() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let pool_id = create_pool(&env, &client, &token);
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &100);
+    client.contribute(&pool_id, &donor, &token, &100, &false);
+
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        for key in [
+            SecondStorageKey::PoolDonation(pool_id, 0),
+            SecondStorageKey::PoolDonationCount(pool_id),
+            SecondStorageKey::DonorPools(donor.clone()),
+        ] {
+            assert!(storage.get_ttl(&key) >= PERSISTENT_BUMP_AMOUNT);
+        }
+    });
+}
@@ -0,0 +1,228 @@
+#![cfg(test)]
+
+use crate::{
+    base::{errors::CrowdfundingError, types::PoolConfig},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, BytesN, Env, String,
+};
+
+const DELAY: u64 = 86_400;
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    let contact = Address::generate(env);
+    client.set_emergency_contact(&contact);
+
+    (client, token, contact)
+}
+
+// Leaves 1_000 of the token owed to a pool sponsor.
+fn create_pool(env: &Env, client: &CrowdfundingContractClient<'_>, token: &Address) {
+    let creator = Address::generate(env);
+    let config = PoolConfig {
+        name: String::from_str(env, "Pool"),
+        description: String::from_str(env, "Emergency test"),
+        target_amount: 1_000,
+        min_contribution: 0,
+        is_private: false,
+        duration: 86_400,
+        created_at: env.ledger().timestamp(),
+        token_address: token.clone(),
+        validator: Some(creator.clone()),
+    };
+
+    StellarAssetClient::new(env, token).mint(&creator, &config.target_amount);
+    client.create_pool(&creator, &config);
+}
+
+#[test]
+fn test_recipient_must_be_contact_or_safe_address() {
+    let env = Env::default();
+    let (client, token, _) = setup(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_request_emergency_withdraw(&token, &100, &stranger),
+        Err(Ok(CrowdfundingError::InvalidEmergencyRecipient))
+    );
+
+    client.set_emergency_safe_address(&stranger, &true);
+    assert!(!client.is_emergency_safe_address(&stranger));
+    env.ledger().set_timestamp(1_000 + DELAY);
+    client.execute_emergency_safe_address(&stranger);
+    assert!(client.is_emergency_safe_address(&stranger));
+    client.request_emergency_withdraw(&token, &100, &stranger);
+
+    let pending = client.get_pending_emergency_withdraw().unwrap();
+    assert_eq!(pending.recipient, stranger);
+    assert_eq!(pending.amount, 100);
+    assert!(!pending.guardian_approved);
+}
+
+#[test]
+fn test_execute_pays_the_recipient() {
+    let env = Env::default();
+    let (client, token, contact) = setup(&env);
+    StellarAssetClient::new(&env, &token).mint(&client.address, &300);
+
+    client.request_emergency_withdraw(&token, &300, &contact);
+    env.ledger().set_timestamp(1_000 + DELAY);
+    client.execute_emergency_withdraw();
+
+    assert_eq!(TokenClient::new(&env, &token).balance(&contact), 300);
+    assert_eq!(client.get_pending_emergency_withdraw(), None);
+}
+
+#[test]
+fn test_guardian_cosign_allows_exceeding_surplus() {
+    let env = Env::default();
+    let (client, token, contact) = setup(&env);
+    create_pool(&env, &client, &token);
+
+    client.request_emergency_withdraw(&token, &1_000, &contact);
+    env.ledger().set_timestamp(1_000 + DELAY);
+    assert_eq!(
+        client.try_execute_emergency_withdraw(),
+        Err(Ok(CrowdfundingError::LiabilityExceeded))
+    );
+
+    client.approve_emergency_withdraw();
+    assert!(
+        client
+            .get_pending_emergency_withdraw()
+            .unwrap()
+            .guardian_approved
+    );
+    client.execute_emergency_withdraw();
+
+    assert_eq!(TokenClient::new(&env, &token).balance(&contact), 1_000);
+}
+
+#[test]
+fn test_cancel_clears_pending_request() {
+    let env = Env::default();
+    let (client, token, contact) = setup(&env);
+
+    assert_eq!(
+        client.try_cancel_emergency_withdraw(),
+        Err(Ok(CrowdfundingError::EmergencyWithdrawalNotRequested))
+    );
+
+    client.request_emergency_withdraw(&token, &100, &contact);
+    client.cancel_emergency_withdraw();
+    assert_eq!(client.get_pending_emergency_withdraw(), None);
+
+    // A fresh request can be queued once the old one is gone
+    client.request_emergency_withdraw(&token, &50, &contact);
+    assert_eq!(client.get_pending_emergency_withdraw().unwrap().amount, 50);
+}
+
+#[test]
+fn test_safe_address_waits_out_the_delay() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    assert_eq!(
+        client.try_execute_emergency_safe_address(&recipient),
+        Err(Ok(CrowdfundingError::EmergencyChangeNotScheduled))
+    );
+
+    client.set_emergency_safe_address(&recipient, &true);
+    let pending = client.get_pending_safe_address(&recipient).unwrap();
+    assert_eq!(pending.requested_at, 1_000);
+
+    env.ledger().set_timestamp(1_000 + DELAY - 1);
+    assert_eq!(
+        client.try_execute_emergency_safe_address(&recipient),
+        Err(Ok(CrowdfundingError::EmergencyChangeDelayNotPassed))
+    );
+
+    env.ledger().set_timestamp(1_000 + DELAY);
+    client.execute_emergency_safe_address(&recipient);
+    assert!(client.is_emergency_safe_address(&recipient));
+    assert_eq!(client.get_pending_safe_address(&recipient), None);
+
+    client.set_emergency_safe_address(&recipient, &false);
+    assert!(!client.is_emergency_safe_address(&recipient));
+}
+
+#[test]
+fn test_contact_replacement_waits_out_the_delay() {
+    let env = Env::default();
+    let (client, _, contact) = setup(&env);
+    let replacement = Address::generate(&env);
+
+    client.set_emergency_contact(&replacement);
+    assert_eq!(client.get_emergency_contact(), contact);
+    assert_eq!(
+        client.get_pending_emergency_contact().unwrap().address,
+        replacement
+    );
+
+    env.ledger().set_timestamp(1_000 + DELAY - 1);
+    assert_eq!(
+        client.try_execute_emergency_contact_change(),
+        Err(Ok(CrowdfundingError::EmergencyChangeDelayNotPassed))
+    );
+
+    env.ledger().set_timestamp(1_000 + DELAY);
+    client.execute_emergency_contact_change();
+    assert_eq!(client.get_emergency_contact(), replacement);
+    assert_eq!(client.get_pending_emergency_contact(), None);
+}
+
+#[test]
+fn test_changes_refused_while_withdrawal_pending() {
+    let env = Env::default();
+    let (client, token, contact) = setup(&env);
+    let other = Address::generate(&env);
+
+    client.set_emergency_contact(&other);
+    client.request_emergency_withdraw(&token, &100, &contact);
+
+    assert_eq!(
+        client.try_set_emergency_contact(&other),
+        Err(Ok(CrowdfundingError::EmergencyRequestPending))
+    );
+    assert_eq!(
+        client.try_set_emergency_safe_address(&other, &true),
+        Err(Ok(CrowdfundingError::EmergencyRequestPending))
+    );
+
+    // A contact scheduled before the request cannot land inside its window
+    env.ledger().set_timestamp(1_000 + DELAY);
+    assert_eq!(
+        client.try_execute_emergency_contact_change(),
+        Err(Ok(CrowdfundingError::EmergencyRequestPending))
+    );
+    assert_eq!(client.get_emergency_contact(), contact);
+}
+
+#[test]
+fn test_contact_change_refused_while_upgrade_pending() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    client.upgrade_contract(&BytesN::from_array(&env, &[1; 32]));
+
+    assert_eq!(
+        client.try_set_emergency_contact(&Address::generate(&env)),
+        Err(Ok(CrowdfundingError::EmergencyRequestPending))
+    );
+}
@@ -1,13 +1,14 @@
 #![cfg(test)]
 
 use crate::{
-    base::{
-        errors::CrowdfundingError,
-        types::PoolConfig,
-    },
+    base::{errors::CrowdfundingError, types::PoolConfig},
     contract::{FundEduContract, FundEduContractClient},
 };
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Bytes, BytesN, Env, String,
+};
 
 fn setup(env: &Env) -> (FundEduContractClient<'_>, Address, Address) {
     env.mock_all_auths();
@@ -46,8 +47,10 @@ fn test_fund_edu_create_pool_success() {
         duration: 30 * 24 * 60 * 60,
         created_at: env.ledger().timestamp(),
         token_address: token_address.clone(),
+        validator: Some(creator.clone()),
     };
 
+    StellarAssetClient::new(&env, &token_address).mint(&creator, &config.target_amount);
     let pool_id = client.create_pool(&creator, &config);
     assert_eq!(pool_id, 1);
 
@@ -84,9 +87,84 @@ fn test_fund_edu_create_pool_paused_returns_error() {
         is_private: false,
         duration: 86_400,
         created_at: env.ledger().timestamp(),
+        validator: Some(creator.clone()),
         token_address,
     };
 
     let result = client.try_create_pool(&creator, &config);
     assert_eq!(result, Err(Ok(CrowdfundingError::ContractPaused)));
 }
+
+#[test]
+fn test_fund_edu_application_review_and_payout() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let sponsor = Address::generate(&env);
+    let validator = Address::generate(&env);
+    let student = Address::generate(&env);
+    let config = PoolConfig {
+        name: String::from_str(&env, "STEM 2026 Q1"),
+        description: String::from_str(&env, "Scholarship for STEM students"),
+        target_amount: 5_000,
+        min_contribution: 0,
+        is_private: false,
+        duration: 30 * 24 * 60 * 60,
+        created_at: env.ledger().timestamp(),
+        token_address: token_address.clone(),
+        validator: Some(validator.clone()),
+    };
+
+    StellarAssetClient::new(&env, &token_address).mint(&sponsor, &config.target_amount);
+    let pool_id = client.create_pool(&sponsor, &config);
+
+    let credentials: BytesN<32> = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"transcript"))
+        .into();
+    client.apply_for_scholarship(&pool_id, &student, &credentials, &None);
+
+    // Only the validator named on the pool may review
+    let result = client.try_approve_application(&pool_id, &student, &sponsor, &None);
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+
+    client.approve_application(&pool_id, &student, &validator, &None);
+    client.claim_pool_funds(&pool_id, &student);
+
+    assert_eq!(
+        TokenClient::new(&env, &token_address).balance(&student),
+        5_000
+    );
+}
+
+#[test]
+fn test_fund_edu_unreviewed_applicant_cannot_claim() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    let sponsor = Address::generate(&env);
+    let student = Address::generate(&env);
+    let config = PoolConfig {
+        name: String::from_str(&env, "Arts 2026"),
+        description: String::from_str(&env, "Scholarship for arts students"),
+        target_amount: 1_000,
+        min_contribution: 0,
+        is_private: false,
+        duration: 30 * 24 * 60 * 60,
+        created_at: env.ledger().timestamp(),
+        token_address: token_address.clone(),
+        validator: Some(sponsor.clone()),
+    };
+
+    StellarAssetClient::new(&env, &token_address).mint(&sponsor, &config.target_amount);
+    let pool_id = client.create_pool(&sponsor, &config);
+
+    let credentials: BytesN<32> = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"portfolio"))
+        .into();
+    client.apply_for_scholarship(&pool_id, &student, &credentials, &None);
+
+    let result = client.try_claim_pool_funds(&pool_id, &student);
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+}
@@ -3,10 +3,10 @@ extern crate std;
 
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
-    Address, Env, String,
+    Address, BytesN, Env, String,
 };
 
-use crate::base::types::{PoolConfig, PoolState};
+use crate::base::types::PoolConfig;
 use crate::crowdfunding::{CrowdfundingContract, CrowdfundingContractClient};
 
 fn create_token_contract<'a>(
@@ -37,9 +37,8 @@ fn test_fund_edu_lifecycle() {
     let admin = Address::generate(&env);
     let sponsor = Address::generate(&env);
     let student = Address::generate(&env);
-    // In this MVP, the contract admin acts as the default validator since they can call
-    // `verify_cause`. Alternatively, an admin verifies causes directly.
-    let validator = admin.clone();
+    // The sponsor names the validator who reviews applications to the pool
+    let validator = Address::generate(&env);
 
     // 1. Setup Token and Contract
     let token_admin = Address::generate(&env);
@@ -66,23 +65,23 @@ fn test_fund_edu_lifecycle() {
         duration: 30 * 24 * 60 * 60,
         created_at: env.ledger().timestamp(),
         token_address: token_address.clone(),
+        validator: Some(validator.clone()),
     };
 
     let pool_id = client.create_pool(&sponsor, &config);
 
-    // Sponsor funds the pool
-    client.contribute(&pool_id, &sponsor, &token_address, &deposit_amount, &false);
+    // Creating the pool takes the sponsor's deposit
     assert_eq!(token.balance(&sponsor), 0);
     assert_eq!(token.balance(&contract_id), deposit_amount); // Pool owns funds
 
     // 3. Student Applies
-    // Student registers by making a 0-amount contribution
-    client.contribute(&pool_id, &student, &token_address, &0i128, &false);
+    // Student submits a commitment to their off-chain credentials
+    let credentials_hash = BytesN::from_array(&env, &[7u8; 32]);
+    client.apply_for_scholarship(&pool_id, &student, &credentials_hash, &None);
 
     // 4. Approve
-    // Validator (Admin in this case) reviews and approves the student off-chain, records on-chain
-    client.verify_cause(&student);
-    assert!(client.is_cause_verified(&student));
+    // The pool's validator reviews the credentials off-chain and approves on-chain
+    client.approve_application(&pool_id, &student, &validator, &None);
 
     // 5. Claim
     // Student executes `claim_pool_funds` after being approved
@@ -51,8 +51,10 @@ fn test_get_pool_contributions_paginated_with_10_contributors() {
         token_address: token_client.address.clone(),
         duration: 30 * 24 * 60 * 60, // 30 days
         created_at: env.ledger().timestamp(),
+        validator: Some(creator.clone()),
     };
 
+    token_client.mint(&creator, &pool_config.target_amount);
     let pool_id = client.create_pool(&creator, &pool_config);
 
     // Create 10 contributors and have them contribute
@@ -129,8 +131,10 @@ fn test_get_pool_contributions_paginated_empty_pool() {
         token_address: token_client.address.clone(),
         duration: 30 * 24 * 60 * 60,
         created_at: env.ledger().timestamp(),
+        validator: Some(creator.clone()),
     };
 
+    token_client.mint(&creator, &pool_config.target_amount);
     let pool_id = client.create_pool(&creator, &pool_config);
 
     // Test: Fetch from empty pool
@@ -168,8 +172,10 @@ fn test_get_pool_contributions_paginated_single_contributor_multiple_contributio
         token_address: token_client.address.clone(),
         duration: 30 * 24 * 60 * 60,
         created_at: env.ledger().timestamp(),
+        validator: Some(creator.clone()),
     };
 
+    token_client.mint(&creator, &pool_config.target_amount);
     let pool_id = client.create_pool(&creator, &pool_config);
 
     // Single contributor makes multiple contributions
@@ -0,0 +1,157 @@
+#![cfg(test)]
+
+use super::create_pool;
+use crate::{
+    base::{errors::CrowdfundingError, types::SubscriptionTarget},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, BytesN, Env, String,
+};
+
+/// Shared setup with an emergency contact in place.
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    let (client, token) = super::setup(env);
+    client.set_emergency_contact(&Address::generate(env));
+    (client, token)
+}
+
+#[test]
+fn test_guardian_can_pause_but_not_unpause() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    client.guardian_pause();
+    assert!(client.is_paused());
+    assert_eq!(
+        client.try_guardian_pause(),
+        Err(Ok(CrowdfundingError::ContractAlreadyPaused))
+    );
+
+    // Resuming stays with the admin
+    client.unpause();
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_guardian_actions_require_a_contact() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = CrowdfundingContractClient::new(&env, &env.register(CrowdfundingContract, ()));
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &0);
+
+    assert_eq!(
+        client.try_guardian_pause(),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+}
+
+#[test]
+fn test_guardian_vetoes_pending_withdrawal_and_upgrade() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+
+    client.request_emergency_withdraw(&token, &100, &client.get_emergency_contact());
+    client.veto_emergency_withdraw();
+    assert_eq!(client.get_pending_emergency_withdraw(), None);
+    assert_eq!(
+        client.try_veto_emergency_withdraw(),
+        Err(Ok(CrowdfundingError::EmergencyWithdrawalNotRequested))
+    );
+
+    client.upgrade_contract(&BytesN::from_array(&env, &[7; 32]));
+    assert_eq!(
+        client.try_execute_upgrade(),
+        Err(Ok(CrowdfundingError::UpgradeDelayNotPassed))
+    );
+    client.veto_upgrade();
+    assert_eq!(client.get_pending_upgrade(), None);
+
+    env.ledger().set_timestamp(1_000 + 86_400);
+    assert_eq!(
+        client.try_execute_upgrade(),
+        Err(Ok(CrowdfundingError::UpgradeNotScheduled))
+    );
+}
+
+#[test]
+fn test_frozen_pool_rejects_contributions_until_unfrozen() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let pool_id = create_pool(&env, &client, &token, &Address::generate(&env));
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &200);
+
+    client.freeze_pool(&pool_id);
+    assert!(client.is_pool_frozen(&pool_id));
+    assert_eq!(
+        client.try_contribute(&pool_id, &donor, &token, &100, &false),
+        Err(Ok(CrowdfundingError::PoolFrozen))
+    );
+
+    client.unfreeze_pool(&pool_id);
+    client.contribute(&pool_id, &donor, &token, &100, &false);
+    assert_eq!(client.get_pool_donations(&pool_id, &0, &10).len(), 1);
+}
+
+#[test]
+fn test_frozen_pool_holds_subscription_payments() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let pool_id = create_pool(&env, &client, &token, &Address::generate(&env));
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &100);
+    TokenClient::new(&env, &token).approve(
+        &donor,
+        &client.address,
+        &100,
+        &(env.ledger().sequence() + 10_000),
+    );
+    let id = client.create_subscription(
+        &donor,
+        &SubscriptionTarget::Pool(pool_id),
+        &100,
+        &86_400,
+        &1,
+    );
+
+    client.freeze_pool(&pool_id);
+    assert_eq!(
+        client.try_process_subscription(&id),
+        Err(Ok(CrowdfundingError::PoolFrozen))
+    );
+    assert_eq!(TokenClient::new(&env, &token).balance(&donor), 100);
+
+    client.unfreeze_pool(&pool_id);
+    client.process_subscription(&id);
+    assert_eq!(TokenClient::new(&env, &token).balance(&donor), 0);
+}
+
+#[test]
+fn test_frozen_campaign_rejects_donations() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let campaign_id = BytesN::from_array(&env, &[9; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(&env, "Frozen"),
+        &Address::generate(&env),
+        &1_000,
+        &10_000,
+        &token,
+    );
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &100);
+
+    client.freeze_campaign(&campaign_id);
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &token, &100),
+        Err(Ok(CrowdfundingError::CampaignFrozen))
+    );
+
+    client.unfreeze_campaign(&campaign_id);
+    assert!(!client.is_campaign_frozen(&campaign_id));
+    client.donate(&campaign_id, &donor, &token, &100);
+}
@@ -1,3 +1,13 @@
+use crate::{
+    base::types::PoolConfig,
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, String,
+};
+
 // mod blacklist_test; // Features not yet implemented
 mod batch_claim_test;
 mod fund_edu_contract_test;
@@ -15,9 +25,83 @@ mod pool_lifecycle_events_test;
 mod pool_remaining_time_test;
 mod renounce_admin_test;
 mod set_platform_fee_bps_test;
-// mod update_pool_metadata_test; // Features not yet implemented
+mod update_pool_metadata_test;
 // mod upgrade_contract_test; // broken on missing hello_world.wasm
 mod validate_string_length_test;
 mod verify_cause;
 mod withdraw_platform_fees_test;
 mod application_test;
+mod pool_match_test;
+mod subscription_test;
+mod pledge_test;
+mod overfunding_test;
+mod campaign_update_test;
+mod campaign_metadata_test;
+mod discovery_test;
+mod portfolio_test;
+mod donation_ledger_test;
+mod solvency_test;
+mod emergency_withdraw_test;
+mod guardian_test;
+mod pool_expiry_test;
+mod pool_extension_test;
+mod pool_cancellation_test;
+mod pool_params_test;
+
+// ---------------------------------------------------------------------------
+// Shared helpers
+// ---------------------------------------------------------------------------
+
+/// Registers the contract at timestamp 1_000 and initializes it with a fresh
+/// token and no creation fee.
+pub(crate) fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    (client, token)
+}
+
+/// Creates a one-day pool with a target of 1_000, validated by its creator.
+pub(crate) fn create_pool(
+    env: &Env,
+    client: &CrowdfundingContractClient<'_>,
+    token: &Address,
+    creator: &Address,
+) -> u64 {
+    create_pool_with(env, client, token, creator, 1_000, 86_400)
+}
+
+/// Creates a pool validated by its creator, minting them the `target` the
+/// contract takes as the sponsor deposit.
+pub(crate) fn create_pool_with(
+    env: &Env,
+    client: &CrowdfundingContractClient<'_>,
+    token: &Address,
+    creator: &Address,
+    target: i128,
+    duration: u64,
+) -> u64 {
+    let config = PoolConfig {
+        name: String::from_str(env, "Pool"),
+        description: String::from_str(env, "Test pool"),
+        target_amount: target,
+        min_contribution: 0,
+        is_private: false,
+        duration,
+        created_at: env.ledger().timestamp(),
+        token_address: token.clone(),
+        validator: Some(creator.clone()),
+    };
+
+    StellarAssetClient::new(env, token).mint(creator, &target);
+    client.create_pool(creator, &config)
+}
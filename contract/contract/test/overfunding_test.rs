@@ -0,0 +1,165 @@
+#![cfg(test)]
+
+use crate::{
+    base::{errors::CrowdfundingError, types::OverfundingPolicy},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    vec, Address, BytesN, Env, String,
+};
+
+const DEADLINE: u64 = 10_000;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address, BytesN<32>) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    let creator = Address::generate(env);
+    let campaign_id = BytesN::from_array(env, &[4; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Stretch"),
+        &creator,
+        &1_000,
+        &DEADLINE,
+        &token,
+    );
+
+    (client, token, creator, campaign_id)
+}
+
+fn donor(env: &Env, token: &Address, balance: i128) -> Address {
+    let donor = Address::generate(env);
+    StellarAssetClient::new(env, token).mint(&donor, &balance);
+    donor
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_hard_cap_accepts_only_the_remainder() {
+    let env = Env::default();
+    let (client, token, _, campaign_id) = setup(&env);
+    let alice = donor(&env, &token, 1_500);
+
+    client.donate(&campaign_id, &alice, &token, &900);
+    client.donate(&campaign_id, &alice, &token, &500);
+
+    assert_eq!(client.get_total_raised(&campaign_id), 1_000);
+    assert_eq!(TokenClient::new(&env, &token).balance(&alice), 500);
+
+    let result = client.try_donate(&campaign_id, &alice, &token, &100);
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignAlreadyFunded)));
+}
+
+#[test]
+fn test_stretch_cap_raises_the_ceiling() {
+    let env = Env::default();
+    let (client, token, creator, campaign_id) = setup(&env);
+    let alice = donor(&env, &token, 2_000);
+
+    client.set_overfunding_policy(
+        &campaign_id,
+        &creator,
+        &OverfundingPolicy::StretchCap(1_500),
+        &vec![&env, 1_200, 1_500],
+    );
+    client.donate(&campaign_id, &alice, &token, &2_000);
+
+    assert_eq!(client.get_total_raised(&campaign_id), 1_500);
+    assert_eq!(TokenClient::new(&env, &token).balance(&alice), 500);
+    assert_eq!(
+        client.get_overfunding_policy(&campaign_id).stretch_goals,
+        vec![&env, 1_200, 1_500]
+    );
+}
+
+#[test]
+fn test_unlimited_policy_accepts_everything() {
+    let env = Env::default();
+    let (client, token, creator, campaign_id) = setup(&env);
+    let alice = donor(&env, &token, 3_000);
+
+    client.set_overfunding_policy(
+        &campaign_id,
+        &creator,
+        &OverfundingPolicy::Unlimited,
+        &vec![&env],
+    );
+    client.donate(&campaign_id, &alice, &token, &1_000);
+    client.donate(&campaign_id, &alice, &token, &2_000);
+
+    assert_eq!(client.get_total_raised(&campaign_id), 3_000);
+}
+
+#[test]
+fn test_pledges_are_clamped_to_the_cap() {
+    let env = Env::default();
+    let (client, token, creator, campaign_id) = setup(&env);
+    let alice = donor(&env, &token, 1_500);
+    TokenClient::new(&env, &token).approve(
+        &alice,
+        &client.address,
+        &1_500,
+        &(env.ledger().sequence() + 10_000),
+    );
+
+    client.enable_pledge_mode(&campaign_id, &creator);
+    client.pledge(&campaign_id, &alice, &1_500);
+
+    assert_eq!(client.get_outstanding_pledges(&campaign_id), 1_000);
+    assert_eq!(client.get_pledge(&campaign_id, &alice).amount, 1_000);
+}
+
+#[test]
+fn test_set_overfunding_policy_validation() {
+    let env = Env::default();
+    let (client, _, creator, campaign_id) = setup(&env);
+    let outsider = Address::generate(&env);
+
+    let result = client.try_set_overfunding_policy(
+        &campaign_id,
+        &outsider,
+        &OverfundingPolicy::Unlimited,
+        &vec![&env],
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+
+    let invalid = [
+        (OverfundingPolicy::StretchCap(1_000), vec![&env]),
+        (OverfundingPolicy::HardCap, vec![&env, 1_200]),
+        (OverfundingPolicy::StretchCap(1_500), vec![&env, 1_600]),
+        (OverfundingPolicy::Unlimited, vec![&env, 1_500, 1_200]),
+        (OverfundingPolicy::Unlimited, vec![&env, 900]),
+    ];
+    for (policy, goals) in invalid {
+        let result = client.try_set_overfunding_policy(&campaign_id, &creator, &policy, &goals);
+        assert_eq!(result, Err(Ok(CrowdfundingError::InvalidOverfundingPolicy)));
+    }
+
+    env.ledger().set_timestamp(DEADLINE);
+    let result = client.try_set_overfunding_policy(
+        &campaign_id,
+        &creator,
+        &OverfundingPolicy::Unlimited,
+        &vec![&env],
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignExpired)));
+}
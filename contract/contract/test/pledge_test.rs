@@ -0,0 +1,213 @@
+#![cfg(test)]
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        types::{CampaignLifecycleStatus, PledgeStatus},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, BytesN, Env, String,
+};
+
+const DEADLINE: u64 = 10_000;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address, BytesN<32>) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    let creator = Address::generate(env);
+    let campaign_id = BytesN::from_array(env, &[3; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "All or nothing"),
+        &creator,
+        &1_000,
+        &DEADLINE,
+        &token,
+    );
+    client.enable_pledge_mode(&campaign_id, &creator);
+
+    (client, token, campaign_id)
+}
+
+/// Creates a donor holding `balance` who has approved the contract for `allowance`.
+fn donor(
+    env: &Env,
+    client: &CrowdfundingContractClient<'_>,
+    token: &Address,
+    balance: i128,
+    allowance: i128,
+) -> Address {
+    let donor = Address::generate(env);
+    StellarAssetClient::new(env, token).mint(&donor, &balance);
+    TokenClient::new(env, token).approve(
+        &donor,
+        &client.address,
+        &allowance,
+        &(env.ledger().sequence() + 10_000),
+    );
+    donor
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_pledge_records_without_transfer() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    let alice = donor(&env, &client, &token, 600, 600);
+
+    client.pledge(&campaign_id, &alice, &600);
+
+    assert_eq!(TokenClient::new(&env, &token).balance(&alice), 600);
+    assert_eq!(client.get_outstanding_pledges(&campaign_id), 600);
+    assert_eq!(client.get_campaign_balance(&campaign_id), 0);
+    assert_eq!(
+        client.get_pledge(&campaign_id, &alice).status,
+        PledgeStatus::Pending
+    );
+}
+
+#[test]
+fn test_pledge_requires_covering_allowance() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    let alice = donor(&env, &client, &token, 600, 500);
+
+    client.pledge(&campaign_id, &alice, &300);
+    let result = client.try_pledge(&campaign_id, &alice, &300);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InsufficientAllowance)));
+}
+
+#[test]
+fn test_donate_is_refused_in_pledge_mode() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    let alice = donor(&env, &client, &token, 100, 0);
+
+    let result = client.try_donate(&campaign_id, &alice, &token, &100);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PledgeModeActive)));
+}
+
+#[test]
+fn test_collect_pledges_in_batches_once_goal_met() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    let alice = donor(&env, &client, &token, 600, 600);
+    let bob = donor(&env, &client, &token, 500, 500);
+
+    client.pledge(&campaign_id, &alice, &600);
+    client.pledge(&campaign_id, &bob, &400);
+
+    let result = client.try_collect_pledges(&campaign_id, &10);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PledgeCollectionNotOpen)));
+
+    env.ledger().set_timestamp(DEADLINE);
+    assert_eq!(
+        client.get_campaign_status(&campaign_id),
+        CampaignLifecycleStatus::Successful
+    );
+
+    assert_eq!(client.collect_pledges(&campaign_id, &1), 1);
+    assert_eq!(client.get_campaign_balance(&campaign_id), 600);
+    assert_eq!(client.collect_pledges(&campaign_id, &1), 1);
+    assert_eq!(client.collect_pledges(&campaign_id, &1), 0);
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&alice), 0);
+    assert_eq!(token_client.balance(&bob), 100);
+    assert_eq!(client.get_campaign_balance(&campaign_id), 1_000);
+    assert_eq!(client.get_outstanding_pledges(&campaign_id), 0);
+    assert_eq!(client.get_contribution(&campaign_id, &bob), 400);
+}
+
+#[test]
+fn test_nothing_moves_when_campaign_expires_short_of_goal() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    let alice = donor(&env, &client, &token, 400, 400);
+
+    client.pledge(&campaign_id, &alice, &400);
+    env.ledger().set_timestamp(DEADLINE);
+
+    assert_eq!(
+        client.get_campaign_status(&campaign_id),
+        CampaignLifecycleStatus::Expired
+    );
+    let result = client.try_collect_pledges(&campaign_id, &10);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PledgeGoalNotReached)));
+    assert_eq!(TokenClient::new(&env, &token).balance(&alice), 400);
+}
+
+#[test]
+fn test_uncollectible_pledge_blocks_collection() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    let alice = donor(&env, &client, &token, 800, 800);
+    let bob = donor(&env, &client, &token, 200, 200);
+
+    client.pledge(&campaign_id, &alice, &800);
+    client.pledge(&campaign_id, &bob, &200);
+    TokenClient::new(&env, &token).approve(&bob, &client.address, &0, &0);
+
+    env.ledger().set_timestamp(DEADLINE);
+    let result = client.try_collect_pledges(&campaign_id, &10);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PledgeGoalNotReached)));
+
+    assert_eq!(
+        client.get_pledge(&campaign_id, &alice).status,
+        PledgeStatus::Pending
+    );
+    assert_eq!(TokenClient::new(&env, &token).balance(&alice), 800);
+    assert_eq!(client.get_campaign_balance(&campaign_id), 0);
+}
+
+#[test]
+fn test_revoked_allowance_mid_collection_cancels_campaign() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    let alice = donor(&env, &client, &token, 800, 800);
+    let bob = donor(&env, &client, &token, 200, 200);
+
+    client.pledge(&campaign_id, &alice, &800);
+    client.pledge(&campaign_id, &bob, &200);
+
+    env.ledger().set_timestamp(DEADLINE);
+    assert_eq!(client.collect_pledges(&campaign_id, &1), 1);
+    TokenClient::new(&env, &token).approve(&bob, &client.address, &0, &0);
+    assert_eq!(client.collect_pledges(&campaign_id, &1), 1);
+
+    assert_eq!(
+        client.get_pledge(&campaign_id, &bob).status,
+        PledgeStatus::Failed
+    );
+    assert_eq!(
+        client.get_campaign_status(&campaign_id),
+        CampaignLifecycleStatus::Cancelled
+    );
+    let result = client.try_collect_pledges(&campaign_id, &1);
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignCancelled)));
+
+    // The pledge already collected goes back to its donor
+    client.refund_campaign(&campaign_id, &alice);
+    assert_eq!(TokenClient::new(&env, &token).balance(&alice), 800);
+}
@@ -0,0 +1,195 @@
+#![cfg(test)]
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        types::{PoolConfig, PoolMetadata, PoolState},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, Env, String,
+};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    (client, token)
+}
+
+fn create_pool(
+    env: &Env,
+    client: &CrowdfundingContractClient<'_>,
+    token: &Address,
+    creator: &Address,
+) -> u64 {
+    let config = PoolConfig {
+        name: String::from_str(env, "Pool"),
+        description: String::from_str(env, "Cancellation test"),
+        target_amount: 1_000,
+        min_contribution: 0,
+        is_private: false,
+        duration: 86_400,
+        created_at: env.ledger().timestamp(),
+        token_address: token.clone(),
+        validator: Some(creator.clone()),
+    };
+
+    StellarAssetClient::new(env, token).mint(creator, &config.target_amount);
+    client.create_pool(creator, &config)
+}
+
+#[test]
+fn test_cancelled_pool_refunds_immediately() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let pool_id = create_pool(&env, &client, &token, &creator);
+
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &250);
+    client.contribute(&pool_id, &donor, &token, &250, &false);
+
+    env.ledger().set_timestamp(2_000);
+    let reason = String::from_str(&env, "Programme withdrawn");
+    assert_eq!(client.cancel_pool(&pool_id, &creator, &reason), 1_000);
+
+    let cancellation = client.get_pool_cancellation(&pool_id).unwrap();
+    assert_eq!(cancellation.cancelled_by, creator);
+    assert_eq!(cancellation.reason, reason);
+    assert_eq!(cancellation.cancelled_at, 2_000);
+
+    // Well before the deadline, let alone the grace period
+    client.refund(&pool_id, &donor);
+    assert_eq!(TokenClient::new(&env, &token).balance(&donor), 250);
+
+    // The deposit and the refund leave nothing owed and nothing held
+    let report = client.get_solvency(&token);
+    assert_eq!(report.balance, 0);
+    assert_eq!(report.liabilities, 0);
+    assert_eq!(report.surplus, 0);
+}
+
+#[test]
+fn test_cancelled_pool_blocks_contributions_and_claims() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let pool_id = create_pool(&env, &client, &token, &creator);
+
+    let student = Address::generate(&env);
+    client.verify_cause(&student);
+    StellarAssetClient::new(&env, &token).mint(&student, &100);
+    client.contribute(&pool_id, &student, &token, &50, &false);
+
+    client.cancel_pool(&pool_id, &creator, &String::from_str(&env, "Fraud"));
+
+    assert_eq!(
+        client.try_contribute(&pool_id, &student, &token, &50, &false),
+        Err(Ok(CrowdfundingError::InvalidPoolState))
+    );
+    assert_eq!(
+        client.try_claim_pool_funds(&pool_id, &student),
+        Err(Ok(CrowdfundingError::InvalidPoolState))
+    );
+}
+
+#[test]
+fn test_finalize_records_missed_target_cancellation() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "No sponsor deposit"),
+        external_url: String::from_str(&env, ""),
+        image_hash: String::from_str(&env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(&env, "Saved"),
+        &metadata,
+        &Address::generate(&env),
+        &1_000,
+        &(1_000 + 86_400),
+        &None,
+        &None,
+    );
+    assert_eq!(client.get_pool_cancellation(&pool_id), None);
+
+    env.ledger().set_timestamp(1_000 + 86_400);
+    assert_eq!(client.finalize_pool(&pool_id), PoolState::Cancelled);
+
+    let cancellation = client.get_pool_cancellation(&pool_id).unwrap();
+    assert_eq!(cancellation.cancelled_by, client.address);
+    assert_eq!(
+        cancellation.reason,
+        String::from_str(&env, "target not reached")
+    );
+}
+
+#[test]
+fn test_refund_after_missed_target_returns_drawn_match() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "Matched, no sponsor deposit"),
+        external_url: String::from_str(&env, ""),
+        image_hash: String::from_str(&env, ""),
+    };
+    let creator = Address::generate(&env);
+    let pool_id = client.save_pool(
+        &String::from_str(&env, "Saved"),
+        &metadata,
+        &creator,
+        &1_000,
+        &(1_000 + 86_400),
+        &None,
+        &None,
+    );
+
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&creator, &500);
+    StellarAssetClient::new(&env, &token).mint(&donor, &200);
+    client.set_pool_match(&pool_id, &creator, &500, &5_000, &1_000);
+    client.contribute(&pool_id, &donor, &token, &200, &false);
+
+    env.ledger().set_timestamp(1_000 + 86_400);
+    assert_eq!(client.finalize_pool(&pool_id), PoolState::Cancelled);
+
+    // The whole match budget, drawn or not, goes back when the pool misses
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&creator), 500);
+    assert_eq!(client.get_match_status(&pool_id).total_matched, 0);
+
+    client.refund(&pool_id, &donor);
+    assert_eq!(token_client.balance(&donor), 200);
+    assert_eq!(token_client.balance(&creator), 500);
+    let report = client.get_solvency(&token);
+    assert_eq!(report.balance, 0);
+    assert_eq!(report.liabilities, 0);
+    assert_eq!(report.surplus, 0);
+}
+
+#[test]
+fn test_cancellation_reason_length_is_bounded() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let pool_id = create_pool(&env, &client, &token, &creator);
+
+    let reason = String::from_str(&env, &"x".repeat(501));
+    assert_eq!(
+        client.try_cancel_pool(&pool_id, &creator, &reason),
+        Err(Ok(CrowdfundingError::InvalidMetadata))
+    );
+}
@@ -5,7 +5,7 @@ use crate::{
     crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
 };
 use soroban_sdk::{
-    testutils::Address as _,
+    testutils::{Address as _, Ledger},
     token::{Client as TokenClient, StellarAssetClient},
     Address, Env, String,
 };
@@ -39,6 +39,7 @@ fn pool_config(env: &Env, token: &Address, target: i128) -> PoolConfig {
         duration: 86_400,
         created_at: env.ledger().timestamp(),
         token_address: token.clone(),
+        validator: Some(token.clone()),
     }
 }
 
@@ -76,7 +77,7 @@ fn test_create_pool_transfers_tokens_to_contract() {
     // The client's contract address is the one that received the funds.
     // We verify via get_pool_balance instead of raw token balance to stay
     // within the contract's own accounting.
-    let locked = client.get_pool_balance(&pool_id).unwrap();
+    let locked = client.get_pool_balance(&pool_id);
     assert_eq!(locked, target, "PoolBalance must equal target_amount");
 }
 
@@ -92,7 +93,7 @@ fn test_create_pool_pool_balance_equals_target_amount() {
     let pool_id = client.create_pool(&sponsor, &pool_config(&env, &token, target));
 
     assert_eq!(
-        client.get_pool_balance(&pool_id).unwrap(),
+        client.get_pool_balance(&pool_id),
         target,
         "locked balance must equal the pool's target_amount"
     );
@@ -113,7 +114,7 @@ fn test_create_pool_metrics_total_raised_equals_deposit() {
     let pool = client.get_pool(&pool_id).unwrap();
     assert_eq!(pool.target_amount, target);
     // Verify via get_pool_balance (the canonical locked-balance query)
-    assert_eq!(client.get_pool_balance(&pool_id).unwrap(), target);
+    assert_eq!(client.get_pool_balance(&pool_id), target);
 }
 
 #[test]
@@ -178,7 +179,141 @@ fn test_multiple_pools_track_balances_independently() {
     let pool1 = client.create_pool(&sponsor1, &pool_config(&env, &token, target1));
     let pool2 = client.create_pool(&sponsor2, &pool_config(&env, &token, target2));
 
-    assert_eq!(client.get_pool_balance(&pool1).unwrap(), target1);
-    assert_eq!(client.get_pool_balance(&pool2).unwrap(), target2);
+    assert_eq!(client.get_pool_balance(&pool1), target1);
+    assert_eq!(client.get_pool_balance(&pool2), target2);
     assert_ne!(pool1, pool2, "pool IDs must be distinct");
 }
+
+#[test]
+fn test_fund_pool_tops_up_balance() {
+    let env = Env::default();
+    let (client, _, token) = setup(&env);
+    let sponsor = Address::generate(&env);
+
+    mint(&env, &token, &sponsor, 15_000);
+    let pool_id = client.create_pool(&sponsor, &pool_config(&env, &token, 10_000));
+
+    client.fund_pool(&pool_id, &sponsor, &5_000);
+
+    assert_eq!(client.get_pool_balance(&pool_id), 15_000);
+    assert_eq!(TokenClient::new(&env, &token).balance(&sponsor), 0);
+}
+
+#[test]
+fn test_fund_pool_rejects_non_sponsor_and_bad_amounts() {
+    let env = Env::default();
+    let (client, _, token) = setup(&env);
+    let sponsor = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    mint(&env, &token, &sponsor, 10_000);
+    mint(&env, &token, &outsider, 10_000);
+    let pool_id = client.create_pool(&sponsor, &pool_config(&env, &token, 10_000));
+
+    let result = client.try_fund_pool(&pool_id, &outsider, &1_000);
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+
+    let result = client.try_fund_pool(&pool_id, &sponsor, &0);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidAmount)));
+
+    let result = client.try_fund_pool(&pool_id, &sponsor, &1_000);
+    assert_eq!(
+        result,
+        Err(Ok(CrowdfundingError::InsufficientSponsorBalance))
+    );
+}
+
+#[test]
+fn test_deactivate_pool_returns_unallocated_balance() {
+    let env = Env::default();
+    let (client, _, token) = setup(&env);
+    let sponsor = Address::generate(&env);
+
+    mint(&env, &token, &sponsor, 12_000);
+    let pool_id = client.create_pool(&sponsor, &pool_config(&env, &token, 10_000));
+    client.fund_pool(&pool_id, &sponsor, &2_000);
+
+    let returned = client.deactivate_pool(&pool_id, &sponsor);
+
+    assert_eq!(returned, 12_000);
+    assert_eq!(TokenClient::new(&env, &token).balance(&sponsor), 12_000);
+    assert_eq!(client.get_pool_balance(&pool_id), 0);
+
+    let result = client.try_fund_pool(&pool_id, &sponsor, &1_000);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolState)));
+}
+
+#[test]
+fn test_deactivate_pool_is_sponsor_only() {
+    let env = Env::default();
+    let (client, _, token) = setup(&env);
+    let sponsor = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    mint(&env, &token, &sponsor, 10_000);
+    let pool_id = client.create_pool(&sponsor, &pool_config(&env, &token, 10_000));
+
+    let result = client.try_deactivate_pool(&pool_id, &outsider);
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+    assert_eq!(client.get_pool_balance(&pool_id), 10_000);
+}
+
+#[test]
+fn test_reclaim_sponsor_deposit_after_unclaimed_expiry() {
+    let env = Env::default();
+    let (client, _, token) = setup(&env);
+    let sponsor = Address::generate(&env);
+
+    mint(&env, &token, &sponsor, 10_000);
+    let pool_id = client.create_pool(&sponsor, &pool_config(&env, &token, 10_000));
+
+    let result = client.try_reclaim_sponsor_deposit(&pool_id, &sponsor);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolNotExpired)));
+
+    // The payout keeps priority for a week after the deadline
+    env.ledger().set_timestamp(86_400);
+    let result = client.try_reclaim_sponsor_deposit(&pool_id, &sponsor);
+    assert_eq!(
+        result,
+        Err(Ok(CrowdfundingError::RefundGracePeriodNotPassed))
+    );
+
+    env.ledger().set_timestamp(86_400 + 604_800);
+    assert_eq!(client.reclaim_sponsor_deposit(&pool_id, &sponsor), 10_000);
+    assert_eq!(TokenClient::new(&env, &token).balance(&sponsor), 10_000);
+    assert_eq!(client.get_pool_balance(&pool_id), 0);
+
+    let result = client.try_reclaim_sponsor_deposit(&pool_id, &sponsor);
+    assert_eq!(result, Err(Ok(CrowdfundingError::NothingToReclaim)));
+}
+
+#[test]
+fn test_reclaim_sponsor_deposit_is_sponsor_only() {
+    let env = Env::default();
+    let (client, _, token) = setup(&env);
+    let sponsor = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    mint(&env, &token, &sponsor, 10_000);
+    let pool_id = client.create_pool(&sponsor, &pool_config(&env, &token, 10_000));
+    env.ledger().set_timestamp(86_400 + 604_800);
+
+    let result = client.try_reclaim_sponsor_deposit(&pool_id, &outsider);
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+    assert_eq!(client.get_pool_balance(&pool_id), 10_000);
+}
+
+#[test]
+fn test_reclaim_after_deactivation_has_nothing_left() {
+    let env = Env::default();
+    let (client, _, token) = setup(&env);
+    let sponsor = Address::generate(&env);
+
+    mint(&env, &token, &sponsor, 10_000);
+    let pool_id = client.create_pool(&sponsor, &pool_config(&env, &token, 10_000));
+    client.deactivate_pool(&pool_id, &sponsor);
+
+    // Cancelled pools need no grace period, but deactivation already paid out
+    let result = client.try_reclaim_sponsor_deposit(&pool_id, &sponsor);
+    assert_eq!(result, Err(Ok(CrowdfundingError::NothingToReclaim)));
+}
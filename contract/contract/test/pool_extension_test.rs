@@ -0,0 +1,163 @@
+#![cfg(test)]
+
+use super::{create_pool, setup};
+use crate::{
+    base::{errors::CrowdfundingError, types::PoolMetadata},
+    crowdfunding::CrowdfundingContractClient,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, Env, String,
+};
+
+const DAY: u64 = 86_400;
+
+// Saved pools hold no sponsor deposit, so they start underfunded.
+fn save_pool(env: &Env, client: &CrowdfundingContractClient<'_>, creator: &Address) -> u64 {
+    let metadata = PoolMetadata {
+        description: String::from_str(env, "Extension test"),
+        external_url: String::from_str(env, ""),
+        image_hash: String::from_str(env, ""),
+    };
+    client.save_pool(
+        &String::from_str(env, "Pool"),
+        &metadata,
+        creator,
+        &1_000,
+        &(1_000 + DAY),
+        &None,
+        &None,
+    )
+}
+
+#[test]
+fn test_creator_extends_underfunded_pool() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let creator = Address::generate(&env);
+    let pool_id = save_pool(&env, &client, &creator);
+
+    client.extend_pool_deadline(&pool_id, &creator, &(1_000 + 10 * DAY));
+
+    let extension = client.get_pool_extension(&pool_id).unwrap();
+    assert_eq!(extension.previous_deadline, 1_000 + DAY);
+    assert_eq!(extension.new_deadline, 1_000 + 10 * DAY);
+    assert_eq!(extension.opt_out_until, 1_000 + 7 * DAY);
+    assert_eq!(client.get_pool_remaining_time(&pool_id), 10 * DAY);
+}
+
+#[test]
+fn test_extension_bounds() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let creator = Address::generate(&env);
+    let pool_id = save_pool(&env, &client, &creator);
+
+    assert_eq!(
+        client.try_extend_pool_deadline(&pool_id, &Address::generate(&env), &(1_000 + 2 * DAY)),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_extend_pool_deadline(&pool_id, &creator, &(1_000 + DAY)),
+        Err(Ok(CrowdfundingError::InvalidPoolDeadline))
+    );
+    assert_eq!(
+        client.try_extend_pool_deadline(&pool_id, &creator, &(1_000 + 91 * DAY)),
+        Err(Ok(CrowdfundingError::InvalidPoolDeadline))
+    );
+
+    env.ledger().set_timestamp(1_000 + DAY);
+    assert_eq!(
+        client.try_extend_pool_deadline(&pool_id, &creator, &(1_000 + 2 * DAY)),
+        Err(Ok(CrowdfundingError::PoolExpired))
+    );
+}
+
+#[test]
+fn test_funded_pool_cannot_be_extended() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let pool_id = save_pool(&env, &client, &creator);
+
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &1_000);
+    client.contribute(&pool_id, &donor, &token, &1_000, &false);
+
+    assert_eq!(
+        client.try_extend_pool_deadline(&pool_id, &creator, &(1_000 + 2 * DAY)),
+        Err(Ok(CrowdfundingError::PoolTargetReached))
+    );
+}
+
+#[test]
+fn test_sponsor_deposit_does_not_count_towards_target() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let pool_id = create_pool(&env, &client, &token, &creator);
+
+    client.extend_pool_deadline(&pool_id, &creator, &(1_000 + 2 * DAY));
+    assert_eq!(
+        client.get_pool_extension(&pool_id).unwrap().new_deadline,
+        1_000 + 2 * DAY
+    );
+}
+
+#[test]
+fn test_existing_contributors_may_opt_out() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let pool_id = save_pool(&env, &client, &creator);
+    let token_client = TokenClient::new(&env, &token);
+
+    let early = Address::generate(&env);
+    let late = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&early, &500);
+    StellarAssetClient::new(&env, &token).mint(&late, &200);
+
+    assert_eq!(
+        client.try_opt_out_of_pool_extension(&pool_id, &early),
+        Err(Ok(CrowdfundingError::OptOutUnavailable))
+    );
+
+    client.contribute(&pool_id, &early, &token, &300, &false);
+    env.ledger().set_timestamp(2_000);
+    client.extend_pool_deadline(&pool_id, &creator, &(1_000 + 10 * DAY));
+    env.ledger().set_timestamp(3_000);
+    client.contribute(&pool_id, &early, &token, &200, &false);
+    client.contribute(&pool_id, &late, &token, &200, &false);
+
+    // Only what was given before the extension comes back
+    assert_eq!(client.opt_out_of_pool_extension(&pool_id, &early), 300);
+    assert_eq!(token_client.balance(&early), 300);
+    assert_eq!(
+        client.try_opt_out_of_pool_extension(&pool_id, &early),
+        Err(Ok(CrowdfundingError::OptOutUnavailable))
+    );
+    assert_eq!(
+        client.try_opt_out_of_pool_extension(&pool_id, &late),
+        Err(Ok(CrowdfundingError::NoContributionToRefund))
+    );
+}
+
+#[test]
+fn test_opt_out_window_closes() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let pool_id = save_pool(&env, &client, &creator);
+
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &100);
+    client.contribute(&pool_id, &donor, &token, &100, &false);
+    client.extend_pool_deadline(&pool_id, &creator, &(1_000 + 30 * DAY));
+
+    env.ledger().set_timestamp(1_000 + 7 * DAY + 1);
+    assert_eq!(
+        client.try_opt_out_of_pool_extension(&pool_id, &donor),
+        Err(Ok(CrowdfundingError::OptOutUnavailable))
+    );
+}
@@ -6,7 +6,7 @@ use crate::{
 };
 use soroban_sdk::{
     symbol_short,
-    testutils::{Address as _, Events},
+    testutils::{Address as _, Events, Ledger},
     token::StellarAssetClient,
     Address, Env, String, Symbol,
 };
@@ -26,7 +26,7 @@ fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
     (client, admin, token)
 }
 
-fn make_pool_config(env: &Env, token: &Address) -> PoolConfig {
+fn make_pool_config(env: &Env, token: &Address, creator: &Address) -> PoolConfig {
     PoolConfig {
         name: String::from_str(env, "Lifecycle Pool"),
         description: String::from_str(env, "Testing pool lifecycle events"),
@@ -36,6 +36,7 @@ fn make_pool_config(env: &Env, token: &Address) -> PoolConfig {
         duration: 86_400,
         created_at: env.ledger().timestamp(),
         token_address: token.clone(),
+        validator: Some(creator.clone()),
     }
 }
 
@@ -45,7 +46,7 @@ fn mint_and_create(
     token: &Address,
     creator: &Address,
 ) -> u64 {
-    let cfg = make_pool_config(env, token);
+    let cfg = make_pool_config(env, token, creator);
     StellarAssetClient::new(env, token).mint(creator, &cfg.target_amount);
     client.create_pool(creator, &cfg)
 }
@@ -172,7 +173,7 @@ fn test_pool_pau_event_emitted_when_pool_paused() {
     let creator = Address::generate(&env);
 
     let pool_id = mint_and_create(&env, &client, &token, &creator);
-    client.update_pool_state(&pool_id, &PoolState::Paused);
+    client.update_pool_state(&pool_id, &creator, &PoolState::Paused);
 
     let pool_pau = symbol_short!("PoolPau");
     let found = env.events().all().iter().any(|(_, topics, _)| {
@@ -195,7 +196,9 @@ fn test_pool_pau_not_emitted_for_non_pause_transitions() {
     let creator = Address::generate(&env);
 
     let pool_id = mint_and_create(&env, &client, &token, &creator);
-    client.update_pool_state(&pool_id, &PoolState::Completed);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 86_401);
+    client.update_pool_state(&pool_id, &creator, &PoolState::Completed);
 
     let pool_pau = symbol_short!("PoolPau");
     let found = env.events().all().iter().any(|(_, topics, _)| {
@@ -218,7 +221,7 @@ fn test_pool_pau_topic_contains_pool_id() {
     let creator = Address::generate(&env);
 
     let pool_id = mint_and_create(&env, &client, &token, &creator);
-    client.update_pool_state(&pool_id, &PoolState::Paused);
+    client.update_pool_state(&pool_id, &creator, &PoolState::Paused);
 
     let pool_pau = symbol_short!("PoolPau");
     let found = env.events().all().iter().any(|(_, topics, _)| {
@@ -250,7 +253,7 @@ fn test_pool_state_updated_event_reflects_accurate_state() {
     let creator = Address::generate(&env);
 
     let pool_id = mint_and_create(&env, &client, &token, &creator);
-    client.update_pool_state(&pool_id, &PoolState::Paused);
+    client.update_pool_state(&pool_id, &creator, &PoolState::Paused);
 
     let state_updated = Symbol::new(&env, "pool_state_updated");
     let found = env.events().all().iter().any(|(_, topics, data)| {
@@ -0,0 +1,202 @@
+#![cfg(test)]
+
+use super::{create_pool, setup};
+use crate::base::errors::CrowdfundingError;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, Bytes, BytesN, Env, String,
+};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn mint(env: &Env, token: &Address, to: &Address, amount: i128) {
+    StellarAssetClient::new(env, token).mint(to, &amount);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_match_applies_ratio_up_to_per_donor_cap() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let donor = Address::generate(&env);
+
+    let pool_id = create_pool(&env, &client, &token, &creator);
+    mint(&env, &token, &sponsor, 1_000);
+    mint(&env, &token, &donor, 800);
+
+    // 1:2 match, at most 300 per donor
+    client.set_pool_match(&pool_id, &sponsor, &1_000, &5_000, &300);
+
+    client.contribute(&pool_id, &donor, &token, &400, &false);
+    assert_eq!(client.get_match_status(&pool_id).total_matched, 200);
+
+    client.contribute(&pool_id, &donor, &token, &400, &false);
+    let status = client.get_match_status(&pool_id);
+    assert_eq!(status.total_matched, 300);
+    assert_eq!(status.remaining, 700);
+}
+
+#[test]
+fn test_match_stops_when_budget_is_exhausted() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    let pool_id = create_pool(&env, &client, &token, &creator);
+    mint(&env, &token, &sponsor, 150);
+    mint(&env, &token, &first, 100);
+    mint(&env, &token, &second, 100);
+
+    client.set_pool_match(&pool_id, &sponsor, &150, &10_000, &1_000);
+
+    client.contribute(&pool_id, &first, &token, &100, &false);
+    client.contribute(&pool_id, &second, &token, &100, &false);
+
+    let status = client.get_match_status(&pool_id);
+    assert_eq!(status.total_matched, 150);
+    assert_eq!(status.remaining, 0);
+}
+
+#[test]
+fn test_matched_funds_are_paid_out_and_unused_match_returned_at_close() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let student = Address::generate(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    let pool_id = create_pool(&env, &client, &token, &creator);
+    mint(&env, &token, &sponsor, 500);
+    mint(&env, &token, &donor, 200);
+
+    client.set_pool_match(&pool_id, &sponsor, &500, &10_000, &1_000);
+    client.contribute(&pool_id, &donor, &token, &200, &false);
+
+    let credentials: BytesN<32> = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"transcript"))
+        .into();
+    client.apply_for_scholarship(&pool_id, &student, &credentials, &None);
+    client.approve_application(&pool_id, &student, &creator, &None);
+    client.claim_pool_funds(&pool_id, &student);
+
+    // Sponsor deposit + donation + matched amount
+    assert_eq!(token_client.balance(&student), 1_400);
+
+    client.close_pool(&pool_id, &creator);
+    assert_eq!(token_client.balance(&sponsor), 300);
+    assert_eq!(client.get_match_status(&pool_id).remaining, 0);
+}
+
+#[test]
+fn test_unused_match_returned_on_deactivation() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+
+    let pool_id = create_pool(&env, &client, &token, &creator);
+    mint(&env, &token, &sponsor, 500);
+    client.set_pool_match(&pool_id, &sponsor, &500, &10_000, &100);
+
+    client.deactivate_pool(&pool_id, &creator);
+
+    assert_eq!(TokenClient::new(&env, &token).balance(&sponsor), 500);
+}
+
+#[test]
+fn test_drawn_match_returned_when_pool_cancelled() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    let pool_id = create_pool(&env, &client, &token, &creator);
+    mint(&env, &token, &sponsor, 500);
+    mint(&env, &token, &donor, 200);
+
+    client.set_pool_match(&pool_id, &sponsor, &500, &10_000, &1_000);
+    client.contribute(&pool_id, &donor, &token, &200, &false);
+    assert_eq!(client.get_match_status(&pool_id).total_matched, 200);
+
+    client.cancel_pool(
+        &pool_id,
+        &creator,
+        &String::from_str(&env, "Sponsor withdrew"),
+    );
+    assert_eq!(token_client.balance(&sponsor), 500);
+    assert_eq!(client.get_match_status(&pool_id).total_matched, 0);
+
+    // The donor gets their own gift back and the match is not paid twice
+    client.refund(&pool_id, &donor);
+    assert_eq!(token_client.balance(&donor), 200);
+    assert_eq!(token_client.balance(&sponsor), 500);
+
+    let report = client.get_solvency(&token);
+    assert_eq!(report.balance, 0);
+    assert_eq!(report.liabilities, 0);
+}
+
+#[test]
+fn test_drawn_match_returned_with_donor_refund() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    let pool_id = create_pool(&env, &client, &token, &creator);
+    mint(&env, &token, &sponsor, 500);
+    mint(&env, &token, &donor, 200);
+
+    client.set_pool_match(&pool_id, &sponsor, &500, &5_000, &1_000);
+    client.contribute(&pool_id, &donor, &token, &200, &false);
+
+    // Past the deadline and the refund grace period
+    env.ledger().set_timestamp(1_000 + 86_400 + 604_800);
+    client.refund(&pool_id, &donor);
+
+    assert_eq!(token_client.balance(&donor), 200);
+    assert_eq!(token_client.balance(&sponsor), 100);
+    let status = client.get_match_status(&pool_id);
+    assert_eq!(status.total_matched, 0);
+    assert_eq!(status.remaining, 400);
+    assert_eq!(client.get_solvency(&token).surplus, 0);
+}
+
+#[test]
+fn test_set_pool_match_validation() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+
+    let pool_id = create_pool(&env, &client, &token, &creator);
+    mint(&env, &token, &sponsor, 1_000);
+
+    let result = client.try_get_match_status(&pool_id);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolMatchNotFound)));
+
+    let result = client.try_set_pool_match(&pool_id, &sponsor, &500, &0, &100);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidAmount)));
+
+    client.set_pool_match(&pool_id, &sponsor, &500, &10_000, &100);
+    let result = client.try_set_pool_match(&pool_id, &sponsor, &500, &10_000, &100);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolMatchAlreadyExists)));
+}
@@ -0,0 +1,237 @@
+#![cfg(test)]
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        types::{PoolConfig, PoolMetadata, PoolParams, MAX_DESCRIPTION_LENGTH},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    vec, Address, Env, String,
+};
+
+const NOW: u64 = 1_000;
+const DEADLINE: u64 = NOW + 86_400;
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    (client, token)
+}
+
+fn params(env: &Env) -> PoolParams {
+    PoolParams::new(env, String::from_str(env, "Scholarships"), 1_000, DEADLINE)
+}
+
+#[test]
+fn test_create_pool_with_all_options() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let validator = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&creator, &1_000);
+
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "Tuition support"),
+        external_url: String::from_str(&env, "https://example.org"),
+        image_hash: String::from_str(&env, "QmHash"),
+    };
+    let pool_id = client.create_pool_with_params(
+        &creator,
+        &params(&env)
+            .with_metadata(metadata.clone())
+            .with_min_contribution(100)
+            .private()
+            .with_validator(validator.clone())
+            .with_multi_sig(1, vec![&env, creator.clone()])
+            .with_sponsor_deposit(),
+    );
+
+    let pool = client.get_pool(&pool_id).unwrap();
+    assert_eq!(pool.description, metadata.description);
+    assert_eq!(pool.min_contribution, 100);
+    assert!(pool.is_private);
+    assert_eq!(pool.validator, Some(validator));
+    assert_eq!(pool.created_at, NOW);
+    assert_eq!(pool.duration, DEADLINE - NOW);
+    assert_eq!(pool.token_address, token);
+
+    assert_eq!(
+        client.get_pool_metadata(&pool_id),
+        (
+            metadata.description,
+            metadata.external_url,
+            metadata.image_hash
+        )
+    );
+    assert_eq!(client.get_pool_balance(&pool_id), 1_000);
+    assert_eq!(TokenClient::new(&env, &token).balance(&creator), 0);
+
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &100);
+    assert!(client
+        .try_contribute(&pool_id, &donor, &token, &50, &false)
+        .is_err());
+}
+
+#[test]
+fn test_create_pool_without_deposit() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+
+    let pool_id = client.create_pool_with_params(&creator, &params(&env));
+
+    let pool = client.get_pool(&pool_id).unwrap();
+    assert_eq!(pool.validator, Some(creator));
+    assert_eq!(pool.min_contribution, 0);
+    assert_eq!(client.get_pool_balance(&pool_id), 0);
+    assert_eq!(TokenClient::new(&env, &token).balance(&client.address), 0);
+}
+
+#[test]
+fn test_create_pool_with_params_validation() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let creator = Address::generate(&env);
+    let long_description =
+        String::from_str(&env, &"a".repeat((MAX_DESCRIPTION_LENGTH + 1) as usize));
+
+    let cases = [
+        (
+            PoolParams::new(&env, String::from_str(&env, ""), 1_000, DEADLINE),
+            CrowdfundingError::InvalidPoolName,
+        ),
+        (
+            PoolParams::new(&env, String::from_str(&env, "Pool"), 0, DEADLINE),
+            CrowdfundingError::InvalidPoolTarget,
+        ),
+        (
+            PoolParams::new(&env, String::from_str(&env, "Pool"), 1_000, NOW),
+            CrowdfundingError::InvalidPoolDeadline,
+        ),
+        (
+            params(&env).with_min_contribution(1_001),
+            CrowdfundingError::InvalidMinContribution,
+        ),
+        (
+            params(&env).with_metadata(PoolMetadata {
+                description: long_description,
+                external_url: String::from_str(&env, ""),
+                image_hash: String::from_str(&env, ""),
+            }),
+            CrowdfundingError::DescriptionTooLong,
+        ),
+        (
+            params(&env).with_multi_sig(2, vec![&env, creator.clone()]),
+            CrowdfundingError::InvalidMultiSigConfig,
+        ),
+        (
+            params(&env).with_sponsor_deposit(),
+            CrowdfundingError::InsufficientSponsorBalance,
+        ),
+    ];
+
+    for (params, expected) in cases {
+        assert_eq!(
+            client.try_create_pool_with_params(&creator, &params),
+            Err(Ok(expected))
+        );
+    }
+}
+
+#[test]
+fn test_create_pool_reports_config_errors() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+
+    let config = PoolConfig {
+        name: String::from_str(&env, "Scholarships"),
+        description: String::from_str(&env, ""),
+        target_amount: 1_000,
+        min_contribution: 1_001,
+        is_private: false,
+        duration: 86_400,
+        created_at: NOW,
+        token_address: token,
+        validator: Some(creator.clone()),
+    };
+    assert_eq!(
+        client.try_create_pool(&creator, &config),
+        Err(Ok(CrowdfundingError::InvalidMinContribution))
+    );
+
+    let config = PoolConfig {
+        min_contribution: 0,
+        duration: 0,
+        ..config
+    };
+    assert_eq!(
+        client.try_create_pool(&creator, &config),
+        Err(Ok(CrowdfundingError::InvalidPoolDuration))
+    );
+
+    let config = PoolConfig {
+        duration: 86_400,
+        validator: None,
+        ..config
+    };
+    assert_eq!(
+        client.try_create_pool(&creator, &config),
+        Err(Ok(CrowdfundingError::ValidatorMissing))
+    );
+}
+
+#[test]
+fn test_create_pool_ignores_supplied_created_at() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&creator, &1_000);
+
+    // A config claiming to have been created long ago
+    let config = PoolConfig {
+        name: String::from_str(&env, "Scholarships"),
+        description: String::from_str(&env, "Tuition support"),
+        target_amount: 1_000,
+        min_contribution: 0,
+        is_private: false,
+        duration: 86_400,
+        created_at: 0,
+        token_address: token,
+        validator: Some(creator.clone()),
+    };
+    let pool_id = client.create_pool(&creator, &config);
+
+    let stored = client.get_pool(&pool_id).unwrap();
+    assert_eq!(stored.created_at, NOW);
+    assert_eq!(stored.duration, 86_400);
+}
+
+#[test]
+fn test_create_pool_with_params_requires_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_create_pool_with_params(&Address::generate(&env), &params(&env)),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+}
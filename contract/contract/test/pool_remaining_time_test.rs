@@ -2,6 +2,7 @@
 
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
     Address, Env, String,
 };
 
@@ -44,8 +45,10 @@ fn test_pool_remaining_time_future() {
         token_address: token_address.clone(),
         duration: 500,
         created_at: env.ledger().timestamp(),
+        validator: Some(creator.clone()),
     };
 
+    StellarAssetClient::new(&env, &token_address).mint(&creator, &config.target_amount);
     let pool_id = client.create_pool(&creator, &config);
 
     let remaining = client.get_pool_remaining_time(&pool_id);
@@ -69,8 +72,10 @@ fn test_pool_remaining_time_expired_returns_zero() {
         token_address: token_address.clone(),
         duration: 100,
         created_at: env.ledger().timestamp(),
+        validator: Some(creator.clone()),
     };
 
+    StellarAssetClient::new(&env, &token_address).mint(&creator, &config.target_amount);
     let pool_id = client.create_pool(&creator, &config);
 
     // Advance the clock past the deadline
@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+use super::{create_pool, setup};
+use crate::{base::types::SecondStorageKey, crowdfunding::CrowdfundingContractClient};
+use soroban_sdk::{
+    testutils::Address as _, token::StellarAssetClient, Address, BytesN, Env, String,
+};
+
+fn create_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient<'_>,
+    token: &Address,
+    creator: &Address,
+    seed: u8,
+) -> BytesN<32> {
+    let id = BytesN::from_array(env, &[seed; 32]);
+    client.create_campaign(
+        &id,
+        &String::from_str(env, "Campaign"),
+        creator,
+        &10_000,
+        &10_000,
+        token,
+    );
+    id
+}
+
+#[test]
+fn test_creator_indexes_list_everything_created() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let first = create_campaign(&env, &client, &token, &alice, 1);
+    create_campaign(&env, &client, &token, &bob, 2);
+    let third = create_campaign(&env, &client, &token, &alice, 3);
+    let pool_id = create_pool(&env, &client, &token, &alice);
+
+    let campaigns = client.get_creator_campaigns(&alice, &0, &10);
+    assert_eq!(campaigns.len(), 2);
+    assert_eq!(campaigns.get(0).unwrap(), first);
+    assert_eq!(
+        client.get_creator_campaigns(&alice, &1, &1).get(0).unwrap(),
+        third
+    );
+
+    assert_eq!(
+        client.get_creator_pools(&alice, &0, &10).get(0),
+        Some(pool_id)
+    );
+    assert_eq!(client.get_creator_pools(&bob, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_creator_index_spans_persistent_pages() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let alice = Address::generate(&env);
+
+    for seed in 0..60 {
+        create_campaign(&env, &client, &token, &alice, seed);
+    }
+
+    // Entries 45..55 straddle the first and second page
+    let page = client.get_creator_campaigns(&alice, &45, &10);
+    assert_eq!(page.len(), 10);
+    assert_eq!(page.get(0).unwrap(), BytesN::from_array(&env, &[45; 32]));
+    assert_eq!(page.get(9).unwrap(), BytesN::from_array(&env, &[54; 32]));
+    assert_eq!(client.get_creator_campaigns(&alice, &55, &10).len(), 5);
+
+    env.as_contract(&client.address, || {
+        let key = SecondStorageKey::CreatorCampaigns(alice.clone());
+        assert!(!env.storage().instance().has(&key));
+        assert_eq!(env.storage().persistent().get::<_, u32>(&key), Some(60));
+    });
+}
+
+#[test]
+fn test_donor_indexes_report_cumulative_amounts() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &1_000);
+
+    let campaign_id = create_campaign(&env, &client, &token, &creator, 1);
+    let pool_id = create_pool(&env, &client, &token, &creator);
+
+    client.donate(&campaign_id, &donor, &token, &100);
+    client.donate(&campaign_id, &donor, &token, &50);
+    client.contribute(&pool_id, &donor, &token, &300, &false);
+
+    let campaigns = client.get_donor_campaigns(&donor, &0, &10);
+    assert_eq!(campaigns.len(), 1);
+    let contribution = campaigns.get(0).unwrap();
+    assert_eq!(contribution.campaign_id, campaign_id);
+    assert_eq!(contribution.amount, 150);
+
+    let pools = client.get_donor_pools(&donor, &0, &10);
+    assert_eq!(pools.len(), 1);
+    assert_eq!(pools.get(0).unwrap().amount, 300);
+
+    assert_eq!(client.get_donor_pools(&donor, &1, &10).len(), 0);
+}
@@ -0,0 +1,177 @@
+#![cfg(test)]
+
+use super::{create_pool, setup};
+use crate::base::{
+    errors::CrowdfundingError,
+    types::{LiabilityBucket, SecondStorageKey},
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env, Map, String,
+};
+
+#[test]
+fn test_campaign_escrow_follows_donations_and_claim() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let campaign_id = BytesN::from_array(&env, &[4; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(&env, "Escrow"),
+        &Address::generate(&env),
+        &500,
+        &10_000,
+        &token,
+    );
+
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &500);
+    client.donate(&campaign_id, &donor, &token, &500);
+
+    let report = client.get_solvency(&token);
+    assert_eq!(report.balance, 500);
+    assert_eq!(report.liabilities, 500);
+    assert_eq!(report.surplus, 0);
+    assert_eq!(
+        report.buckets.get(LiabilityBucket::CampaignEscrow),
+        Some(500)
+    );
+
+    client.claim_campaign_funds(&campaign_id);
+
+    // The platform's cut stays behind and is owed to the platform
+    let report = client.get_solvency(&token);
+    assert_eq!(report.balance, report.liabilities);
+    assert_eq!(report.buckets.get(LiabilityBucket::CampaignEscrow), Some(0));
+    assert_eq!(
+        report.buckets.get(LiabilityBucket::PlatformFees),
+        Some(report.balance)
+    );
+}
+
+#[test]
+fn test_pool_deposits_contributions_and_matches_are_owed() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let pool_id = create_pool(&env, &client, &token, &Address::generate(&env));
+
+    let sponsor = Address::generate(&env);
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&sponsor, &400);
+    StellarAssetClient::new(&env, &token).mint(&donor, &200);
+
+    client.set_pool_match(&pool_id, &sponsor, &400, &10_000, &1_000);
+    client.contribute(&pool_id, &donor, &token, &200, &false);
+
+    let report = client.get_solvency(&token);
+    assert_eq!(report.balance, 1_600);
+    assert_eq!(report.liabilities, 1_600);
+    // Deposit, contribution and the 200 drawn from the match budget
+    assert_eq!(report.buckets.get(LiabilityBucket::PoolFunds), Some(1_400));
+    assert_eq!(report.buckets.get(LiabilityBucket::MatchBudgets), Some(200));
+}
+
+#[test]
+fn test_emergency_withdraw_is_limited_to_surplus() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    create_pool(&env, &client, &token, &Address::generate(&env));
+    let contact = Address::generate(&env);
+    client.set_emergency_contact(&contact);
+
+    // Tokens sent straight to the contract are not owed to anyone
+    StellarAssetClient::new(&env, &token).mint(&client.address, &250);
+    assert_eq!(client.get_solvency(&token).surplus, 250);
+
+    client.request_emergency_withdraw(&token, &1_000, &contact);
+    env.ledger().set_timestamp(1_000 + 86_401);
+    assert_eq!(
+        client.try_execute_emergency_withdraw(),
+        Err(Ok(CrowdfundingError::LiabilityExceeded))
+    );
+
+    StellarAssetClient::new(&env, &token).mint(&client.address, &750);
+    client.execute_emergency_withdraw();
+
+    let report = client.get_solvency(&token);
+    assert_eq!(report.balance, 1_000);
+    assert_eq!(report.liabilities, 1_000);
+    assert_eq!(report.surplus, 0);
+}
+
+#[test]
+fn test_upgraded_balances_must_be_seeded_before_surplus_withdrawals() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let contact = Address::generate(&env);
+    client.set_emergency_contact(&contact);
+
+    // A contract from before liability tracking, holding 1_000 of which 800 is owed
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .remove(&SecondStorageKey::LiabilitiesTracked);
+    });
+    StellarAssetClient::new(&env, &token).mint(&client.address, &1_000);
+    assert_eq!(client.get_solvency(&token).surplus, 1_000);
+
+    client.request_emergency_withdraw(&token, &200, &contact);
+    env.ledger().set_timestamp(1_000 + 86_401);
+    assert_eq!(
+        client.try_execute_emergency_withdraw(),
+        Err(Ok(CrowdfundingError::LiabilitiesNotSeeded))
+    );
+
+    let mut owed = Map::new(&env);
+    owed.set(LiabilityBucket::CampaignEscrow, 500);
+    owed.set(LiabilityBucket::PoolFunds, 300);
+    client.seed_liabilities(&token, &owed);
+    assert!(
+        env.auths().iter().any(|(addr, _)| addr == &contact),
+        "emergency contact must co-sign the seed"
+    );
+    assert_eq!(
+        client.try_seed_liabilities(&token, &owed),
+        Err(Ok(CrowdfundingError::LiabilitiesAlreadySeeded))
+    );
+
+    let report = client.get_solvency(&token);
+    assert_eq!(report.liabilities, 800);
+    assert_eq!(report.surplus, 200);
+
+    client.execute_emergency_withdraw();
+    assert_eq!(client.get_solvency(&token).surplus, 0);
+}
+
+#[test]
+fn test_seeding_needs_an_emergency_contact() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .remove(&SecondStorageKey::LiabilitiesTracked);
+    });
+
+    let mut owed = Map::new(&env);
+    owed.set(LiabilityBucket::CampaignEscrow, 0);
+    assert_eq!(
+        client.try_seed_liabilities(&token, &owed),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+}
+
+#[test]
+fn test_seeding_is_refused_when_liabilities_were_always_tracked() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    client.set_emergency_contact(&Address::generate(&env));
+
+    let mut owed = Map::new(&env);
+    owed.set(LiabilityBucket::CampaignEscrow, 500);
+    assert_eq!(
+        client.try_seed_liabilities(&token, &owed),
+        Err(Ok(CrowdfundingError::LiabilitiesAlreadySeeded))
+    );
+}
@@ -0,0 +1,162 @@
+#![cfg(test)]
+
+use super::{create_pool_with, setup};
+use crate::{
+    base::{errors::CrowdfundingError, types::SubscriptionTarget},
+    crowdfunding::CrowdfundingContractClient,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, BytesN, Env, String,
+};
+
+const MONTH: u64 = 30 * 24 * 60 * 60;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// A year-long pool, so subscriptions can run for several periods.
+fn create_yearly_pool(env: &Env, client: &CrowdfundingContractClient<'_>, token: &Address) -> u64 {
+    create_pool_with(
+        env,
+        client,
+        token,
+        &Address::generate(env),
+        1_000,
+        12 * MONTH,
+    )
+}
+
+/// Mints `amount * periods` to a new donor and approves the contract to pull it.
+fn funded_donor(
+    env: &Env,
+    client: &CrowdfundingContractClient<'_>,
+    token: &Address,
+    amount: i128,
+    periods: u32,
+) -> Address {
+    let donor = Address::generate(env);
+    let total = amount * periods as i128;
+    StellarAssetClient::new(env, token).mint(&donor, &total);
+    TokenClient::new(env, token).approve(
+        &donor,
+        &client.address,
+        &total,
+        &(env.ledger().sequence() + 10_000),
+    );
+    donor
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_pool_subscription_pulls_each_period_when_due() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let pool_id = create_yearly_pool(&env, &client, &token);
+    let donor = funded_donor(&env, &client, &token, 50, 2);
+
+    let id =
+        client.create_subscription(&donor, &SubscriptionTarget::Pool(pool_id), &50, &MONTH, &2);
+
+    client.process_subscription(&id);
+    let result = client.try_process_subscription(&id);
+    assert_eq!(result, Err(Ok(CrowdfundingError::SubscriptionNotDue)));
+
+    env.ledger().set_timestamp(1_000 + MONTH);
+    client.process_subscription(&id);
+
+    let subscription = client.get_subscription(&id);
+    assert_eq!(subscription.remaining_periods, 0);
+    assert!(!subscription.active);
+    assert_eq!(TokenClient::new(&env, &token).balance(&donor), 0);
+
+    env.ledger().set_timestamp(1_000 + 2 * MONTH);
+    let result = client.try_process_subscription(&id);
+    assert_eq!(result, Err(Ok(CrowdfundingError::SubscriptionInactive)));
+}
+
+#[test]
+fn test_campaign_subscription_uses_donation_accounting() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let creator = Address::generate(&env);
+    let campaign_id = BytesN::from_array(&env, &[7; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(&env, "Monthly Campaign"),
+        &creator,
+        &10_000,
+        &(env.ledger().timestamp() + 12 * MONTH),
+        &token,
+    );
+    let donor = funded_donor(&env, &client, &token, 200, 3);
+
+    let id = client.create_subscription(
+        &donor,
+        &SubscriptionTarget::Campaign(campaign_id.clone()),
+        &200,
+        &MONTH,
+        &3,
+    );
+    client.process_subscription(&id);
+
+    assert_eq!(client.get_total_raised(&campaign_id), 200);
+    assert_eq!(client.get_contribution(&campaign_id, &donor), 200);
+    assert_eq!(client.get_donor_count(&campaign_id), 1);
+}
+
+#[test]
+fn test_process_subscription_without_allowance_fails() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let pool_id = create_yearly_pool(&env, &client, &token);
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&donor, &100);
+
+    let id =
+        client.create_subscription(&donor, &SubscriptionTarget::Pool(pool_id), &100, &MONTH, &1);
+
+    let result = client.try_process_subscription(&id);
+    assert_eq!(result, Err(Ok(CrowdfundingError::TokenTransferFailed)));
+    assert!(client.get_subscription(&id).active);
+}
+
+#[test]
+fn test_cancel_subscription_is_donor_only() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let pool_id = create_yearly_pool(&env, &client, &token);
+    let donor = funded_donor(&env, &client, &token, 50, 3);
+    let outsider = Address::generate(&env);
+
+    let id =
+        client.create_subscription(&donor, &SubscriptionTarget::Pool(pool_id), &50, &MONTH, &3);
+
+    let result = client.try_cancel_subscription(&id, &outsider);
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+
+    client.cancel_subscription(&id, &donor);
+    let result = client.try_process_subscription(&id);
+    assert_eq!(result, Err(Ok(CrowdfundingError::SubscriptionInactive)));
+}
+
+#[test]
+fn test_create_subscription_validates_input() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let pool_id = create_yearly_pool(&env, &client, &token);
+    let donor = Address::generate(&env);
+
+    let result =
+        client.try_create_subscription(&donor, &SubscriptionTarget::Pool(pool_id), &0, &MONTH, &1);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidAmount)));
+
+    let result =
+        client.try_create_subscription(&donor, &SubscriptionTarget::Pool(99), &10, &MONTH, &1);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolNotFound)));
+}
@@ -1,13 +1,17 @@
 #![cfg(test)]
 
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
     token::StellarAssetClient,
-    Address, Env, String,
+    Address, Env, FromVal, String, Symbol,
 };
 
 use crate::{
-    base::{errors::CrowdfundingError, types::PoolConfig},
+    base::{
+        errors::CrowdfundingError,
+        types::{PoolConfig, PoolMetadata},
+    },
     crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
 };
 
@@ -40,6 +44,7 @@ fn create_test_pool(env: &Env, client: &CrowdfundingContractClient<'_>, creator:
         duration: 86400, // 1 day
         created_at: 1_000,
         token_address: token_id.clone(),
+        validator: Some(creator.clone()),
     };
 
     StellarAssetClient::new(env, token_id).mint(creator, &config.target_amount);
@@ -185,3 +190,112 @@ fn test_update_pool_metadata_hash_event_emission() {
     let (_, _, image_hash) = client.get_pool_metadata(&pool_id);
     assert_eq!(image_hash, new_hash);
 }
+
+fn metadata(env: &Env, description: &str, url: &str, hash: &str) -> PoolMetadata {
+    PoolMetadata {
+        description: String::from_str(env, description),
+        external_url: String::from_str(env, url),
+        image_hash: String::from_str(env, hash),
+    }
+}
+
+#[test]
+fn test_update_pool_metadata_replaces_all_fields() {
+    let env = Env::default();
+    let (client, _admin, token_id) = setup_test(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_test_pool(&env, &client, &creator, &token_id);
+    assert_eq!(client.get_pool_metadata_revision(&pool_id), 0);
+
+    let revision = client.update_pool_metadata(
+        &pool_id,
+        &creator,
+        &metadata(
+            &env,
+            "Updated plan",
+            "https://nevo.example/pool",
+            "QmUpdated",
+        ),
+    );
+    assert_eq!(revision, 1);
+
+    let (description, external_url, image_hash) = client.get_pool_metadata(&pool_id);
+    assert_eq!(description, String::from_str(&env, "Updated plan"));
+    assert_eq!(
+        external_url,
+        String::from_str(&env, "https://nevo.example/pool")
+    );
+    assert_eq!(image_hash, String::from_str(&env, "QmUpdated"));
+
+    client.update_pool_metadata_hash(&pool_id, &creator, &String::from_str(&env, "QmSecond"));
+    assert_eq!(client.get_pool_metadata_revision(&pool_id), 2);
+}
+
+#[test]
+fn test_update_pool_metadata_emits_both_event_versions() {
+    let env = Env::default();
+    let (client, _admin, token_id) = setup_test(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_test_pool(&env, &client, &creator, &token_id);
+
+    client.update_pool_metadata(&pool_id, &creator, &metadata(&env, "Plan", "", "QmBoth"));
+
+    let names: std::vec::Vec<Symbol> = env
+        .events()
+        .all()
+        .iter()
+        .map(|(_, topics, _)| Symbol::from_val(&env, &topics.get(0).unwrap()))
+        .collect();
+    assert!(names.contains(&Symbol::new(&env, "pool_metadata_updated")));
+    assert!(names.contains(&symbol_short!("PoolUpd")));
+}
+
+#[test]
+fn test_update_pool_metadata_validates_lengths() {
+    let env = Env::default();
+    let (client, _admin, token_id) = setup_test(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_test_pool(&env, &client, &creator, &token_id);
+
+    let mut long_url = std::string::String::from("https://");
+    long_url.push_str(&"a".repeat(200));
+
+    let result = client.try_update_pool_metadata(
+        &pool_id,
+        &creator,
+        &metadata(&env, "Plan", &long_url, "QmHash"),
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidMetadata)));
+    assert_eq!(client.get_pool_metadata_revision(&pool_id), 0);
+}
+
+#[test]
+fn test_metadata_lock_applies_after_first_contribution() {
+    let env = Env::default();
+    let (client, _admin, token_id) = setup_test(&env);
+
+    let creator = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let pool_id = create_test_pool(&env, &client, &creator, &token_id);
+    client.set_pool_metadata_lock(&pool_id, &creator, &true);
+
+    // Still editable until someone contributes
+    client.update_pool_metadata(&pool_id, &creator, &metadata(&env, "Final plan", "", ""));
+
+    StellarAssetClient::new(&env, &token_id).mint(&donor, &500);
+    client.contribute(&pool_id, &donor, &token_id, &500, &false);
+
+    let result =
+        client.try_update_pool_metadata(&pool_id, &creator, &metadata(&env, "Bait", "", ""));
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolMetadataLocked)));
+
+    let result =
+        client.try_update_pool_metadata_hash(&pool_id, &creator, &String::from_str(&env, "QmX"));
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolMetadataLocked)));
+
+    let result = client.try_set_pool_metadata_lock(&pool_id, &creator, &false);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolMetadataLocked)));
+}
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use soroban_sdk::{
-    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
     Address, BytesN, Env, IntoVal,
 };
 
@@ -29,7 +29,7 @@ fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
 }
 
 /// Integration test: proves the full upgrade path works end-to-end.
-/// Uploads a real WASM binary, calls upgrade_contract, and verifies the
+/// Uploads a real WASM binary, schedules and executes the upgrade, and verifies the
 /// contract remains functional (storage intact) after the upgrade.
 #[test]
 fn test_upgrade_contract_succeeds_with_valid_wasm() {
@@ -46,8 +46,11 @@ fn test_upgrade_contract_succeeds_with_valid_wasm() {
     // Upload the contract's own compiled WASM — gives a valid on-ledger hash.
     let new_wasm_hash: BytesN<32> = env.deployer().upload_contract_wasm(upgraded_contract::WASM);
 
-    // Upgrade must succeed: admin is authorized and WASM hash is valid.
+    // Upgrade must succeed once the delay has passed: admin is authorized
+    // and WASM hash is valid.
     client.upgrade_contract(&new_wasm_hash);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 86_400);
+    client.execute_upgrade();
 
     // Contract is still callable after upgrade — storage is preserved.
     let result = client.try_get_pool_remaining_time(&999u64);
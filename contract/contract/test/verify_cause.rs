@@ -110,9 +110,10 @@ fn test_reject_cause_removes_verification_and_emits_event() {
     assert!(client.is_cause_verified(&cause));
 
     client.reject_cause(&cause);
+    // Read events before the next call replaces them
+    let events = env.events().all();
     assert!(!client.is_cause_verified(&cause));
 
-    let events = env.events().all();
     let found = events.iter().any(|(_, topics, data)| {
         if topics.is_empty() {
             return false;
@@ -136,9 +137,9 @@ fn test_reject_cause_on_unverified_address_emits_event() {
 
     // reject without prior verify — should still fire the event
     client.reject_cause(&cause);
+    let events = env.events().all();
     assert!(!client.is_cause_verified(&cause));
 
-    let events = env.events().all();
     let found = events.iter().any(|(_, topics, _)| {
         if topics.is_empty() {
             return false;